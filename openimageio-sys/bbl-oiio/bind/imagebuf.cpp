@@ -28,6 +28,13 @@ bool ImageBuf_write_with_spec(OIIO::ImageBuf const &buf,
   return buf.write(file_name, type_desc, file_format);
 }
 
+/// Writes the pixels into the current subimage of an already opened
+/// `ImageOutput`.
+bool ImageBuf_write_to_output(OIIO::ImageBuf const &buf,
+                              OIIO::ImageOutput *out) {
+  return buf.write(out);
+}
+
 /// Workaround: if we pass in an OIIO::TypeDesc from Rust, we get an "invalid
 /// memory reference" crash here.
 /// TODO: figure out why and use ImageBuf::get_pixels() directly
@@ -38,6 +45,17 @@ bool ImageBuf_get_pixels(OIIO::ImageBuf const &buf, OIIO::ROI roi,
   return buf.get_pixels(roi, type_desc, result);
 }
 
+/// Strided version of `set_pixels()`. Takes a `BASETYPE` for the same reason
+/// as `ImageBuf_get_pixels()`.
+bool ImageBuf_set_pixels_strided(OIIO::ImageBuf &buf, OIIO::ROI roi,
+                                 OIIO::TypeDesc::BASETYPE base_type,
+                                 const void *data, int64_t xstride,
+                                 int64_t ystride, int64_t zstride) {
+  OIIO::TypeDesc type_desc = OIIO::TypeDesc(base_type, 0);
+
+  return buf.set_pixels(roi, type_desc, data, xstride, ystride, zstride);
+}
+
 bool ImageBuf_set_pixels_f64(OIIO::ImageBuf &buf, OIIO::ROI roi,
                              OIIO::cspan<double> pixels) {
   return buf.set_pixels(roi, pixels);
@@ -77,6 +95,12 @@ void ImageBuf_expand_roi_full(OIIO::ImageBuf &buf) {
   buf.set_roi_full(buf.roi());
 }
 
+/// `set_write_format()` takes a `cspan<TypeDesc>` for which we have no binding.
+void ImageBuf_set_write_format(OIIO::ImageBuf &buf,
+                               std::vector<OIIO::TypeDesc> const &formats) {
+  buf.set_write_format(formats);
+}
+
 OIIO::ImageBuf ImageBuf_from_dimensions(int width, int height, int nchannels,
                                         OIIO::TypeDesc format,
                                         OIIO::string_view color_space) {
@@ -184,11 +208,23 @@ BBL_MODULE(oiio) {
                                   OIIO::ImageBuf::WrapMode) const) &
              OIIO::ImageBuf::getpixel,
          "getpixel")
-      //.m(&OIIO::ImageBuf::interppixel)
-      //.m(&OIIO::ImageBuf::interppixel_NDC)
+      .m((void(OIIO::ImageBuf::*)(float, float, float *,
+                                  OIIO::ImageBuf::WrapMode) const) &
+             OIIO::ImageBuf::interppixel,
+         "interppixel")
+      .m((void(OIIO::ImageBuf::*)(float, float, float *,
+                                  OIIO::ImageBuf::WrapMode) const) &
+             OIIO::ImageBuf::interppixel_NDC,
+         "interppixel_NDC")
       //.m(&OIIO::ImageBuf::interppixel_NDC_full)
-      //.m(&OIIO::ImageBuf::interppixel_bicubic)
-      //.m(&OIIO::ImageBuf::interppixel_bicubic_NDC)
+      .m((void(OIIO::ImageBuf::*)(float, float, float *,
+                                  OIIO::ImageBuf::WrapMode) const) &
+             OIIO::ImageBuf::interppixel_bicubic,
+         "interppixel_bicubic")
+      .m((void(OIIO::ImageBuf::*)(float, float, float *,
+                                  OIIO::ImageBuf::WrapMode) const) &
+             OIIO::ImageBuf::interppixel_bicubic_NDC,
+         "interppixel_bicubic_NDC")
 
       //.m(&OIIO::ImageBuf::get_pixels)
       .m(&OIIO::ImageBuf::initialized)
@@ -293,8 +329,10 @@ BBL_MODULE(oiio) {
   // bbl::fn(&bblext::ImageBuf_file_format_name);
   bbl::fn(&bblext::ImageBuf_write);
   bbl::fn(&bblext::ImageBuf_write_with_spec);
+  bbl::fn(&bblext::ImageBuf_write_to_output);
   bbl::fn(&bblext::ImageBuf_get_pixels);
-  // bbl::fn(&bblext::ImageBuf_set_pixels_f16);
+  bbl::fn(&bblext::ImageBuf_set_pixels_strided);
+  bbl::fn(&bblext::ImageBuf_set_pixels_f16);
   bbl::fn(&bblext::ImageBuf_set_pixels_f32);
   bbl::fn(&bblext::ImageBuf_set_pixels_f64);
   // bbl::fn(&bblext::ImageBuf_set_pixels_u64);
@@ -302,6 +340,7 @@ BBL_MODULE(oiio) {
   bbl::fn(&bblext::ImageBuf_set_pixels_u16);
   bbl::fn(&bblext::ImageBuf_set_pixels_u8);
   bbl::fn(&bblext::ImageBuf_expand_roi_full);
+  bbl::fn(&bblext::ImageBuf_set_write_format);
 
   // FIXME: why can we not call ImageSpec::from_dimensions and have it work?
   bbl::fn(&bblext::ImageBuf_from_dimensions);
@@ -3,6 +3,33 @@
 #include <OpenImageIO/imagebufalgo.h>
 #include <OpenImageIO/paramlist.h>
 
+namespace bblext {
+
+/// Workaround: `isConstantColor()` takes a mutable `span<float>` which we do
+/// not bind. So we take a raw pointer and length instead.
+bool ImageBufAlgo_isConstantColor(const OIIO::ImageBuf &src, float threshold,
+                                  float *color, int color_count, OIIO::ROI roi,
+                                  int nthreads) {
+  return OIIO::ImageBufAlgo::isConstantColor(
+      src, threshold, OIIO::span<float>(color, color_count), roi, nthreads);
+}
+
+/// Workaround: `absdiff()` takes `Image_or_Const` arguments which we do not
+/// bind.
+bool ImageBufAlgo_absdiff(OIIO::ImageBuf &dst, const OIIO::ImageBuf &A,
+                          const OIIO::ImageBuf &B, OIIO::ROI roi,
+                          int nthreads) {
+  return OIIO::ImageBufAlgo::absdiff(dst, A, B, roi, nthreads);
+}
+
+/// Workaround: `mul()` takes `Image_or_Const` arguments which we do not bind.
+bool ImageBufAlgo_mul(OIIO::ImageBuf &dst, const OIIO::ImageBuf &A,
+                      const OIIO::ImageBuf &B, OIIO::ROI roi, int nthreads) {
+  return OIIO::ImageBufAlgo::mul(dst, A, B, roi, nthreads);
+}
+
+} // namespace bblext
+
 BBL_MODULE(oiio) {
 
   bbl::Class<OIIO::ImageBufAlgo::CompareResults>()
@@ -102,12 +129,44 @@ BBL_MODULE(oiio) {
                     int))&OIIO::ImageBufAlgo::rotate270,
           "ImageBufAlgo_rotate270");
 
+  // flip()
+  bbl::fn((bool (*)(OIIO::ImageBuf &, const OIIO::ImageBuf &, OIIO::ROI,
+                    int))&OIIO::ImageBufAlgo::flip,
+          "ImageBufAlgo_flip");
+
+  // flop()
+  bbl::fn((bool (*)(OIIO::ImageBuf &, const OIIO::ImageBuf &, OIIO::ROI,
+                    int))&OIIO::ImageBufAlgo::flop,
+          "ImageBufAlgo_flop");
+
+  // transpose()
+  bbl::fn((bool (*)(OIIO::ImageBuf &, const OIIO::ImageBuf &, OIIO::ROI,
+                    int))&OIIO::ImageBufAlgo::transpose,
+          "ImageBufAlgo_transpose");
+
+  // circular_shift()
+  bbl::fn((bool (*)(OIIO::ImageBuf &, const OIIO::ImageBuf &, int, int, int,
+                    OIIO::ROI, int))&OIIO::ImageBufAlgo::circular_shift,
+          "ImageBufAlgo_circular_shift");
+
   // compare()
   bbl::fn((OIIO::ImageBufAlgo::CompareResults(*)(
               const OIIO::ImageBuf &, const OIIO::ImageBuf &, float, float,
               OIIO::ROI, int))&OIIO::ImageBufAlgo::compare,
           "ImageBufAlgo_compare");
 
+  // compare_Yee()
+  bbl::fn((int (*)(const OIIO::ImageBuf &, const OIIO::ImageBuf &,
+                   OIIO::ImageBufAlgo::CompareResults &, float, float,
+                   OIIO::ROI, int))&OIIO::ImageBufAlgo::compare_Yee,
+          "ImageBufAlgo_compare_Yee");
+
+  // absdiff()
+  bbl::fn(&bblext::ImageBufAlgo_absdiff);
+
+  // mul()
+  bbl::fn(&bblext::ImageBufAlgo_mul);
+
   // render_text()
   bbl::fn(
       (bool (*)(OIIO::ImageBuf &, int, int, OIIO::string_view, int,
@@ -116,6 +175,26 @@ BBL_MODULE(oiio) {
                 int, OIIO::ROI, int))&OIIO::ImageBufAlgo::render_text,
       "ImageBufAlgo_render_text");
 
+  // text_size()
+  bbl::fn((OIIO::ROI(*)(OIIO::string_view, int,
+                        OIIO::string_view))&OIIO::ImageBufAlgo::text_size,
+          "ImageBufAlgo_text_size");
+
+  // render_point()
+  bbl::fn((bool (*)(OIIO::ImageBuf &, int, int, OIIO::cspan<float>, OIIO::ROI,
+                    int))&OIIO::ImageBufAlgo::render_point,
+          "ImageBufAlgo_render_point");
+
+  // render_line()
+  bbl::fn((bool (*)(OIIO::ImageBuf &, int, int, int, int, OIIO::cspan<float>,
+                    bool, OIIO::ROI, int))&OIIO::ImageBufAlgo::render_line,
+          "ImageBufAlgo_render_line");
+
+  // render_box()
+  bbl::fn((bool (*)(OIIO::ImageBuf &, int, int, int, int, OIIO::cspan<float>,
+                    bool, OIIO::ROI, int))&OIIO::ImageBufAlgo::render_box,
+          "ImageBufAlgo_render_box");
+
 #if OIIO_VERSION >= OIIO_MAKE_VERSION(2, 5, 0)
   bbl::fn((bool (*)(OIIO::ImageBuf &, const OIIO::ImageBuf &, OIIO::string_view,
                     OIIO::string_view, bool, OIIO::string_view,
@@ -130,6 +209,23 @@ BBL_MODULE(oiio) {
           "ImageBufAlgo_colorconvert");
 #endif
 
+  // ociodisplay()
+#if OIIO_VERSION >= OIIO_MAKE_VERSION(2, 5, 0)
+  bbl::fn((bool (*)(OIIO::ImageBuf &, const OIIO::ImageBuf &, OIIO::string_view,
+                    OIIO::string_view, OIIO::string_view, OIIO::string_view,
+                    bool, OIIO::string_view, OIIO::string_view,
+                    const OIIO::ColorConfig *, OIIO::ROI,
+                    int))&OIIO::ImageBufAlgo::ociodisplay,
+          "ImageBufAlgo_ociodisplay");
+#else
+  bbl::fn((bool (*)(OIIO::ImageBuf &, const OIIO::ImageBuf &, OIIO::string_view,
+                    OIIO::string_view, OIIO::string_view, OIIO::string_view,
+                    bool, OIIO::string_view, OIIO::string_view,
+                    OIIO::ColorConfig *, OIIO::ROI,
+                    int))&OIIO::ImageBufAlgo::ociodisplay,
+          "ImageBufAlgo_ociodisplay");
+#endif
+
   // resample()
   bbl::fn((bool (*)(OIIO::ImageBuf &, const OIIO::ImageBuf &, bool, OIIO::ROI,
                     int))&OIIO::ImageBufAlgo::resample,
@@ -158,6 +254,18 @@ BBL_MODULE(oiio) {
                     int))&OIIO::ImageBufAlgo::cut,
           "ImageBufAlgo_cut");
 
+  // paste()
+  bbl::fn((bool (*)(OIIO::ImageBuf &, int, int, int, int,
+                    const OIIO::ImageBuf &, OIIO::ROI,
+                    int))&OIIO::ImageBufAlgo::paste,
+          "ImageBufAlgo_paste");
+
+  // channel_append()
+  bbl::fn((bool (*)(OIIO::ImageBuf &, const OIIO::ImageBuf &,
+                    const OIIO::ImageBuf &, OIIO::ROI,
+                    int))&OIIO::ImageBufAlgo::channel_append,
+          "ImageBufAlgo_channel_append");
+
   // invert()
   bbl::fn((bool (*)(OIIO::ImageBuf &, const OIIO::ImageBuf &, OIIO::ROI,
                     int))&OIIO::ImageBufAlgo::invert,
@@ -204,6 +312,46 @@ BBL_MODULE(oiio) {
                     int))&OIIO::ImageBufAlgo::convolve,
           "ImageBufAlgo_convolve");
 
+  // fft()
+  bbl::fn((bool (*)(OIIO::ImageBuf &, const OIIO::ImageBuf &, OIIO::ROI,
+                    int))&OIIO::ImageBufAlgo::fft,
+          "ImageBufAlgo_fft");
+
+  // ifft()
+  bbl::fn((bool (*)(OIIO::ImageBuf &, const OIIO::ImageBuf &, OIIO::ROI,
+                    int))&OIIO::ImageBufAlgo::ifft,
+          "ImageBufAlgo_ifft");
+
+  // complex_to_polar()
+  bbl::fn((bool (*)(OIIO::ImageBuf &, const OIIO::ImageBuf &, OIIO::ROI,
+                    int))&OIIO::ImageBufAlgo::complex_to_polar,
+          "ImageBufAlgo_complex_to_polar");
+
+  // polar_to_complex()
+  bbl::fn((bool (*)(OIIO::ImageBuf &, const OIIO::ImageBuf &, OIIO::ROI,
+                    int))&OIIO::ImageBufAlgo::polar_to_complex,
+          "ImageBufAlgo_polar_to_complex");
+
+  // fillholes_pushpull()
+  bbl::fn((bool (*)(OIIO::ImageBuf &, const OIIO::ImageBuf &, OIIO::ROI,
+                    int))&OIIO::ImageBufAlgo::fillholes_pushpull,
+          "ImageBufAlgo_fillholes_pushpull");
+
+  // median_filter()
+  bbl::fn((bool (*)(OIIO::ImageBuf &, const OIIO::ImageBuf &, int, int,
+                    OIIO::ROI, int))&OIIO::ImageBufAlgo::median_filter,
+          "ImageBufAlgo_median_filter");
+
+  // dilate()
+  bbl::fn((bool (*)(OIIO::ImageBuf &, const OIIO::ImageBuf &, int, int,
+                    OIIO::ROI, int))&OIIO::ImageBufAlgo::dilate,
+          "ImageBufAlgo_dilate");
+
+  // erode()
+  bbl::fn((bool (*)(OIIO::ImageBuf &, const OIIO::ImageBuf &, int, int,
+                    OIIO::ROI, int))&OIIO::ImageBufAlgo::erode,
+          "ImageBufAlgo_erode");
+
   bbl::fn((OIIO::ImageBuf(*)(OIIO::string_view, float, float, float,
                              bool))&OIIO::ImageBufAlgo::make_kernel,
           "ImageBufAlgo_from_kernel");
@@ -221,4 +369,22 @@ BBL_MODULE(oiio) {
                     OIIO::cspan<std::string>, bool,
                     int))&OIIO::ImageBufAlgo::channels,
           "ImageBufAlgo_channels");
+
+  // isConstantColor()
+  bbl::fn(&bblext::ImageBufAlgo_isConstantColor);
+
+  // isConstantChannel()
+  bbl::fn((bool (*)(const OIIO::ImageBuf &, int, float, float, OIIO::ROI,
+                    int))&OIIO::ImageBufAlgo::isConstantChannel,
+          "ImageBufAlgo_isConstantChannel");
+
+  // isMonochrome()
+  bbl::fn((bool (*)(const OIIO::ImageBuf &, float, OIIO::ROI,
+                    int))&OIIO::ImageBufAlgo::isMonochrome,
+          "ImageBufAlgo_isMonochrome");
+
+  // nonzero_region()
+  bbl::fn((OIIO::ROI(*)(const OIIO::ImageBuf &, OIIO::ROI,
+                        int))&OIIO::ImageBufAlgo::nonzero_region,
+          "ImageBufAlgo_nonzero_region");
 }
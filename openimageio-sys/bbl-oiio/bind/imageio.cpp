@@ -4,6 +4,14 @@
 
 // bindfile
 
+namespace bblext {
+
+OIIO::ImageOutput *ImageOutputPtr_get(OIIO::ImageOutput::unique_ptr &ptr) {
+  return ptr.get();
+}
+
+} // namespace bblext
+
 BBL_MODULE(oiio) {
 
   bbl::Class<OIIO::ROI>()
@@ -242,6 +250,8 @@ BBL_MODULE(oiio) {
 
   bbl::Enum<OIIO::ImageOutput::OpenMode>();
 
+  bbl::fn(&bblext::ImageOutputPtr_get);
+
   bbl::fn(&OIIO::roi_union);
 
   bbl::fn(&OIIO::roi_intersection);
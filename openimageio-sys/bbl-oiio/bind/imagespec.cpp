@@ -175,6 +175,11 @@ std::vector<std::string> const &
 ImageSpec_get_channelnames(OIIO::ImageSpec const &self) {
   return self.channelnames;
 }
+
+void VecImageSpec_push_back(std::vector<OIIO::ImageSpec> &self,
+                            OIIO::ImageSpec const &value) {
+  self.push_back(value);
+}
 } // namespace bblext
 
 BBL_MODULE(oiio) {
@@ -359,6 +364,11 @@ BBL_MODULE(oiio) {
   bbl::fn(&bblext::ImageSpec_push_channelname);
   bbl::fn(&bblext::ImageSpec_get_channelnames);
 
+  bbl::Class<std::vector<OIIO::ImageSpec>>("VecImageSpec")
+      BBL_STD_VECTOR_METHODS(OIIO::ImageSpec);
+
+  bbl::fn(&bblext::VecImageSpec_push_back);
+
   bbl::Enum<OIIO::ImageSpec::SerialFormat>();
   bbl::Enum<OIIO::ImageSpec::SerialVerbose>();
 }
@@ -3,6 +3,15 @@
 
 #include <OpenImageIO/imagebuf.h>
 
+namespace bblext {
+
+void VecTypeDesc_push_back(std::vector<OIIO::TypeDesc> &self,
+                           OIIO::TypeDesc value) {
+  self.push_back(value);
+}
+
+} // namespace bblext
+
 BBL_MODULE(oiio) {
 
   bbl::Class<OIIO::TypeDesc>()
@@ -62,4 +71,6 @@ BBL_MODULE(oiio) {
 
   bbl::Class<std::vector<OIIO::TypeDesc>>("VecTypeDesc")
       BBL_STD_VECTOR_METHODS(OIIO::TypeDesc);
+
+  bbl::fn(&bblext::VecTypeDesc_push_back);
 }
@@ -442,6 +442,11 @@ pub struct oiio_TypeDesc_t {
     pub arraylen: c_int,
 }
 
+#[repr(C)]
+pub struct oiio_VecImageSpec_t {
+    _unused: [u8; 0],
+}
+
 #[repr(C)]
 pub struct oiio_VecTypeDesc_t {
     _unused: [u8; 0],
@@ -677,6 +682,14 @@ pub fn oiio_ImageBuf_getchannel(_this: *const oiio_ImageBuf_t, x: c_int, y: c_in
 
 pub fn oiio_ImageBuf_getpixel(_this: *const oiio_ImageBuf_t, x: c_int, y: c_int, z: c_int, pixel: *mut c_float, maxchannels: c_int, wrap: oiio_WrapMode) -> c_int;
 
+pub fn oiio_ImageBuf_interppixel(_this: *const oiio_ImageBuf_t, x: c_float, y: c_float, pixel: *mut c_float, wrap: oiio_WrapMode) -> c_int;
+
+pub fn oiio_ImageBuf_interppixel_NDC(_this: *const oiio_ImageBuf_t, s: c_float, t: c_float, pixel: *mut c_float, wrap: oiio_WrapMode) -> c_int;
+
+pub fn oiio_ImageBuf_interppixel_bicubic(_this: *const oiio_ImageBuf_t, x: c_float, y: c_float, pixel: *mut c_float, wrap: oiio_WrapMode) -> c_int;
+
+pub fn oiio_ImageBuf_interppixel_bicubic_NDC(_this: *const oiio_ImageBuf_t, s: c_float, t: c_float, pixel: *mut c_float, wrap: oiio_WrapMode) -> c_int;
+
 pub fn oiio_ImageBuf_initialized(_this: *const oiio_ImageBuf_t, _result: *mut bool) -> c_int;
 
 pub fn oiio_ImageBuf_storage(_this: *const oiio_ImageBuf_t, _result: *mut oiio_IBStorage) -> c_int;
@@ -875,6 +888,14 @@ pub fn oiio_ImageBufSharedPtr_getchannel(_this: *const oiio_ImageBufSharedPtr_t,
 
 pub fn oiio_ImageBufSharedPtr_getpixel(_this: *const oiio_ImageBufSharedPtr_t, x: c_int, y: c_int, z: c_int, pixel: *mut c_float, maxchannels: c_int, wrap: oiio_WrapMode) -> c_int;
 
+pub fn oiio_ImageBufSharedPtr_interppixel(_this: *const oiio_ImageBufSharedPtr_t, x: c_float, y: c_float, pixel: *mut c_float, wrap: oiio_WrapMode) -> c_int;
+
+pub fn oiio_ImageBufSharedPtr_interppixel_NDC(_this: *const oiio_ImageBufSharedPtr_t, s: c_float, t: c_float, pixel: *mut c_float, wrap: oiio_WrapMode) -> c_int;
+
+pub fn oiio_ImageBufSharedPtr_interppixel_bicubic(_this: *const oiio_ImageBufSharedPtr_t, x: c_float, y: c_float, pixel: *mut c_float, wrap: oiio_WrapMode) -> c_int;
+
+pub fn oiio_ImageBufSharedPtr_interppixel_bicubic_NDC(_this: *const oiio_ImageBufSharedPtr_t, s: c_float, t: c_float, pixel: *mut c_float, wrap: oiio_WrapMode) -> c_int;
+
 pub fn oiio_ImageBufSharedPtr_initialized(_this: *const oiio_ImageBufSharedPtr_t, _result: *mut bool) -> c_int;
 
 pub fn oiio_ImageBufSharedPtr_storage(_this: *const oiio_ImageBufSharedPtr_t, _result: *mut oiio_IBStorage) -> c_int;
@@ -1699,6 +1720,20 @@ pub fn oiio_VecTypeDesc_default(_result: *mut *mut oiio_VecTypeDesc_t) -> c_int;
 
 pub fn oiio_VecTypeDesc_dtor(_this: *mut oiio_VecTypeDesc_t) -> c_int;
 
+pub fn oiio_VecTypeDesc_push_back(self_: *mut oiio_VecTypeDesc_t, value: oiio_TypeDesc_t) -> c_int;
+
+pub fn oiio_VecImageSpec_data_const(_this: *const oiio_VecImageSpec_t, _result: *mut *const oiio_ImageSpec_t) -> c_int;
+
+pub fn oiio_VecImageSpec_size(_this: *const oiio_VecImageSpec_t, _result: *mut usize) -> c_int;
+
+pub fn oiio_VecImageSpec_op_index(_this: *const oiio_VecImageSpec_t, __n: usize, _result: *mut *const oiio_ImageSpec_t) -> c_int;
+
+pub fn oiio_VecImageSpec_default(_result: *mut *mut oiio_VecImageSpec_t) -> c_int;
+
+pub fn oiio_VecImageSpec_dtor(_this: *mut oiio_VecImageSpec_t) -> c_int;
+
+pub fn oiio_VecImageSpec_push_back(self_: *mut oiio_VecImageSpec_t, value: *const oiio_ImageSpec_t) -> c_int;
+
 pub fn oiio_ustring_c_str(_this: *const oiio_ustring_t, _result: *mut *const c_char) -> c_int;
 
 pub fn oiio_ustring_data(_this: *const oiio_ustring_t, _result: *mut *const c_char) -> c_int;
@@ -1749,8 +1784,14 @@ pub fn oiio_ImageBuf_write(buf: *const oiio_ImageBuf_t, file_name: *const oiio_S
 
 pub fn oiio_ImageBuf_write_with_spec(buf: *const oiio_ImageBuf_t, file_name: *const oiio_StringView_t, type_desc: oiio_TypeDesc_t, file_format: *const oiio_StringView_t, _result: *mut bool) -> c_int;
 
+pub fn oiio_ImageBuf_write_to_output(buf: *const oiio_ImageBuf_t, out: *mut oiio_ImageOutput_t, _result: *mut bool) -> c_int;
+
 pub fn oiio_ImageBuf_get_pixels(buf: *const oiio_ImageBuf_t, roi: oiio_ROI_t, base_type: oiio_BASETYPE, result: *mut c_void, _result: *mut bool) -> c_int;
 
+pub fn oiio_ImageBuf_set_pixels_strided(buf: *mut oiio_ImageBuf_t, roi: oiio_ROI_t, base_type: oiio_BASETYPE, data: *const c_void, xstride: i64, ystride: i64, zstride: i64, _result: *mut bool) -> c_int;
+
+pub fn oiio_ImageBuf_set_pixels_f16(buf: *mut oiio_ImageBuf_t, roi: oiio_ROI_t, pixels: *mut oiio_CspanF16_t, _result: *mut bool) -> c_int;
+
 pub fn oiio_ImageBuf_set_pixels_f32(buf: *mut oiio_ImageBuf_t, roi: oiio_ROI_t, pixels: *mut oiio_CspanF32_t, _result: *mut bool) -> c_int;
 
 pub fn oiio_ImageBuf_set_pixels_f64(buf: *mut oiio_ImageBuf_t, roi: oiio_ROI_t, pixels: *mut oiio_CspanF64_t, _result: *mut bool) -> c_int;
@@ -1763,6 +1804,8 @@ pub fn oiio_ImageBuf_set_pixels_u8(buf: *mut oiio_ImageBuf_t, roi: oiio_ROI_t, p
 
 pub fn oiio_ImageBuf_expand_roi_full(buf: *mut oiio_ImageBuf_t) -> c_int;
 
+pub fn oiio_ImageBuf_set_write_format(buf: *mut oiio_ImageBuf_t, formats: *const oiio_VecTypeDesc_t) -> c_int;
+
 pub fn oiio_ImageBuf_from_dimensions(width: c_int, height: c_int, nchannels: c_int, format: oiio_TypeDesc_t, color_space: *mut oiio_StringView_t, _result: *mut *mut oiio_ImageBuf_t) -> c_int;
 
 pub fn oiio_ImageBufAlgo_zero(dst: *mut oiio_ImageBuf_t, roi: oiio_ROI_t, nthreads: c_int, _result: *mut bool) -> c_int;
@@ -1795,12 +1838,36 @@ pub fn oiio_ImageBufAlgo_rotate180(dst: *mut oiio_ImageBuf_t, src: *const oiio_I
 
 pub fn oiio_ImageBufAlgo_rotate270(dst: *mut oiio_ImageBuf_t, src: *const oiio_ImageBuf_t, roi: oiio_ROI_t, nthreads: c_int, _result: *mut bool) -> c_int;
 
+pub fn oiio_ImageBufAlgo_flip(dst: *mut oiio_ImageBuf_t, src: *const oiio_ImageBuf_t, roi: oiio_ROI_t, nthreads: c_int, _result: *mut bool) -> c_int;
+
+pub fn oiio_ImageBufAlgo_flop(dst: *mut oiio_ImageBuf_t, src: *const oiio_ImageBuf_t, roi: oiio_ROI_t, nthreads: c_int, _result: *mut bool) -> c_int;
+
+pub fn oiio_ImageBufAlgo_transpose(dst: *mut oiio_ImageBuf_t, src: *const oiio_ImageBuf_t, roi: oiio_ROI_t, nthreads: c_int, _result: *mut bool) -> c_int;
+
+pub fn oiio_ImageBufAlgo_circular_shift(dst: *mut oiio_ImageBuf_t, src: *const oiio_ImageBuf_t, xshift: c_int, yshift: c_int, zshift: c_int, roi: oiio_ROI_t, nthreads: c_int, _result: *mut bool) -> c_int;
+
 pub fn oiio_ImageBufAlgo_compare(A: *const oiio_ImageBuf_t, B: *const oiio_ImageBuf_t, failthresh: c_float, warnthresh: c_float, roi: oiio_ROI_t, nthreads: c_int, _result: *mut oiio_CompareResults_t) -> c_int;
 
+pub fn oiio_ImageBufAlgo_compare_Yee(A: *const oiio_ImageBuf_t, B: *const oiio_ImageBuf_t, result: *mut oiio_CompareResults_t, luminance: c_float, fov: c_float, roi: oiio_ROI_t, nthreads: c_int, _result: *mut c_int) -> c_int;
+
+pub fn oiio_ImageBufAlgo_absdiff(dst: *mut oiio_ImageBuf_t, A: *const oiio_ImageBuf_t, B: *const oiio_ImageBuf_t, roi: oiio_ROI_t, nthreads: c_int, _result: *mut bool) -> c_int;
+
+pub fn oiio_ImageBufAlgo_mul(dst: *mut oiio_ImageBuf_t, A: *const oiio_ImageBuf_t, B: *const oiio_ImageBuf_t, roi: oiio_ROI_t, nthreads: c_int, _result: *mut bool) -> c_int;
+
 pub fn oiio_ImageBufAlgo_render_text(dst: *mut oiio_ImageBuf_t, x: c_int, y: c_int, text: *mut oiio_StringView_t, fontsize: c_int, fontname: *mut oiio_StringView_t, textcolor: *mut oiio_CspanF32_t, alignx: oiio_TextAlignX, aligny: oiio_TextAlignY, shadow: c_int, roi: oiio_ROI_t, nthreads: c_int, _result: *mut bool) -> c_int;
 
+pub fn oiio_ImageBufAlgo_text_size(text: *mut oiio_StringView_t, fontsize: c_int, fontname: *mut oiio_StringView_t, _result: *mut oiio_ROI_t) -> c_int;
+
+pub fn oiio_ImageBufAlgo_render_point(dst: *mut oiio_ImageBuf_t, x: c_int, y: c_int, color: *mut oiio_CspanF32_t, roi: oiio_ROI_t, nthreads: c_int, _result: *mut bool) -> c_int;
+
+pub fn oiio_ImageBufAlgo_render_line(dst: *mut oiio_ImageBuf_t, x1: c_int, y1: c_int, x2: c_int, y2: c_int, color: *mut oiio_CspanF32_t, skip_first_point: bool, roi: oiio_ROI_t, nthreads: c_int, _result: *mut bool) -> c_int;
+
+pub fn oiio_ImageBufAlgo_render_box(dst: *mut oiio_ImageBuf_t, x1: c_int, y1: c_int, x2: c_int, y2: c_int, color: *mut oiio_CspanF32_t, fill: bool, roi: oiio_ROI_t, nthreads: c_int, _result: *mut bool) -> c_int;
+
 pub fn oiio_ImageBufAlgo_colorconvert(dst: *mut oiio_ImageBuf_t, src: *const oiio_ImageBuf_t, fromspace: *mut oiio_StringView_t, tospace: *mut oiio_StringView_t, unpremult: bool, context_key: *mut oiio_StringView_t, context_value: *mut oiio_StringView_t, colorconfig: *const oiio_ColorConfig_t, roi: oiio_ROI_t, nthreads: c_int, _result: *mut bool) -> c_int;
 
+pub fn oiio_ImageBufAlgo_ociodisplay(dst: *mut oiio_ImageBuf_t, src: *const oiio_ImageBuf_t, display: *mut oiio_StringView_t, view: *mut oiio_StringView_t, fromspace: *mut oiio_StringView_t, looks: *mut oiio_StringView_t, unpremult: bool, context_key: *mut oiio_StringView_t, context_value: *mut oiio_StringView_t, colorconfig: *const oiio_ColorConfig_t, roi: oiio_ROI_t, nthreads: c_int, _result: *mut bool) -> c_int;
+
 pub fn oiio_ImageBufAlgo_resample(dst: *mut oiio_ImageBuf_t, src: *const oiio_ImageBuf_t, interpolate: bool, roi: oiio_ROI_t, nthreads: c_int, _result: *mut bool) -> c_int;
 
 pub fn oiio_ImageBufAlgo_resize(dst: *mut oiio_ImageBuf_t, src: *const oiio_ImageBuf_t, options: *mut oiio_ParamValueSpan_t, roi: oiio_ROI_t, nthreads: c_int, _result: *mut bool) -> c_int;
@@ -1811,6 +1878,10 @@ pub fn oiio_ImageBufAlgo_st_warp(dst: *mut oiio_ImageBuf_t, src: *const oiio_Ima
 
 pub fn oiio_ImageBufAlgo_cut(dst: *mut oiio_ImageBuf_t, src: *const oiio_ImageBuf_t, roi: oiio_ROI_t, nthreads: c_int, _result: *mut bool) -> c_int;
 
+pub fn oiio_ImageBufAlgo_paste(dst: *mut oiio_ImageBuf_t, xbegin: c_int, ybegin: c_int, zbegin: c_int, chbegin: c_int, src: *const oiio_ImageBuf_t, srcroi: oiio_ROI_t, nthreads: c_int, _result: *mut bool) -> c_int;
+
+pub fn oiio_ImageBufAlgo_channel_append(dst: *mut oiio_ImageBuf_t, A: *const oiio_ImageBuf_t, B: *const oiio_ImageBuf_t, roi: oiio_ROI_t, nthreads: c_int, _result: *mut bool) -> c_int;
+
 pub fn oiio_ImageBufAlgo_invert(dst: *mut oiio_ImageBuf_t, A: *const oiio_ImageBuf_t, roi: oiio_ROI_t, nthreads: c_int, _result: *mut bool) -> c_int;
 
 pub fn oiio_ImageBufAlgo_channel_sum(dst: *mut oiio_ImageBuf_t, src: *const oiio_ImageBuf_t, weights: *mut oiio_CspanF32_t, roi: oiio_ROI_t, nthreads: c_int, _result: *mut bool) -> c_int;
@@ -1829,6 +1900,22 @@ pub fn oiio_ImageBufAlgo_repremult(dst: *mut oiio_ImageBuf_t, src: *const oiio_I
 
 pub fn oiio_ImageBufAlgo_convolve(dst: *mut oiio_ImageBuf_t, src: *const oiio_ImageBuf_t, kernel: *const oiio_ImageBuf_t, normalize: bool, roi: oiio_ROI_t, nthreads: c_int, _result: *mut bool) -> c_int;
 
+pub fn oiio_ImageBufAlgo_fft(dst: *mut oiio_ImageBuf_t, src: *const oiio_ImageBuf_t, roi: oiio_ROI_t, nthreads: c_int, _result: *mut bool) -> c_int;
+
+pub fn oiio_ImageBufAlgo_ifft(dst: *mut oiio_ImageBuf_t, src: *const oiio_ImageBuf_t, roi: oiio_ROI_t, nthreads: c_int, _result: *mut bool) -> c_int;
+
+pub fn oiio_ImageBufAlgo_complex_to_polar(dst: *mut oiio_ImageBuf_t, src: *const oiio_ImageBuf_t, roi: oiio_ROI_t, nthreads: c_int, _result: *mut bool) -> c_int;
+
+pub fn oiio_ImageBufAlgo_polar_to_complex(dst: *mut oiio_ImageBuf_t, src: *const oiio_ImageBuf_t, roi: oiio_ROI_t, nthreads: c_int, _result: *mut bool) -> c_int;
+
+pub fn oiio_ImageBufAlgo_fillholes_pushpull(dst: *mut oiio_ImageBuf_t, src: *const oiio_ImageBuf_t, roi: oiio_ROI_t, nthreads: c_int, _result: *mut bool) -> c_int;
+
+pub fn oiio_ImageBufAlgo_median_filter(dst: *mut oiio_ImageBuf_t, src: *const oiio_ImageBuf_t, width: c_int, height: c_int, roi: oiio_ROI_t, nthreads: c_int, _result: *mut bool) -> c_int;
+
+pub fn oiio_ImageBufAlgo_dilate(dst: *mut oiio_ImageBuf_t, src: *const oiio_ImageBuf_t, width: c_int, height: c_int, roi: oiio_ROI_t, nthreads: c_int, _result: *mut bool) -> c_int;
+
+pub fn oiio_ImageBufAlgo_erode(dst: *mut oiio_ImageBuf_t, src: *const oiio_ImageBuf_t, width: c_int, height: c_int, roi: oiio_ROI_t, nthreads: c_int, _result: *mut bool) -> c_int;
+
 pub fn oiio_ImageBufAlgo_from_kernel(name: *mut oiio_StringView_t, width: c_float, height: c_float, depth: c_float, normalize: bool, _result: *mut *mut oiio_ImageBuf_t) -> c_int;
 
 pub fn oiio_ImageBufAlgo_computePixelHashSHA1(src: *const oiio_ImageBuf_t, extrainfo: *mut oiio_StringView_t, roi: oiio_ROI_t, blocksize: c_int, nthreads: c_int, _result: *mut *mut oiio_String_t) -> c_int;
@@ -1837,6 +1924,14 @@ pub fn oiio_ImageBufAlgo_crop(dst: *mut oiio_ImageBuf_t, src: *const oiio_ImageB
 
 pub fn oiio_ImageBufAlgo_channels(dst: *mut oiio_ImageBuf_t, src: *const oiio_ImageBuf_t, nchannels: c_int, channelorder: *mut oiio_CspanI32_t, channelvalues: *mut oiio_CspanF32_t, newchannelnames: *mut oiio_CspanString_t, shuffle_channel_names: bool, nthreads: c_int, _result: *mut bool) -> c_int;
 
+pub fn oiio_ImageBufAlgo_isConstantColor(src: *const oiio_ImageBuf_t, threshold: c_float, color: *mut c_float, color_count: c_int, roi: oiio_ROI_t, nthreads: c_int, _result: *mut bool) -> c_int;
+
+pub fn oiio_ImageBufAlgo_isConstantChannel(src: *const oiio_ImageBuf_t, channel: c_int, val: c_float, threshold: c_float, roi: oiio_ROI_t, nthreads: c_int, _result: *mut bool) -> c_int;
+
+pub fn oiio_ImageBufAlgo_isMonochrome(src: *const oiio_ImageBuf_t, threshold: c_float, roi: oiio_ROI_t, nthreads: c_int, _result: *mut bool) -> c_int;
+
+pub fn oiio_ImageBufAlgo_nonzero_region(src: *const oiio_ImageBuf_t, roi: oiio_ROI_t, nthreads: c_int, _result: *mut oiio_ROI_t) -> c_int;
+
 pub fn oiio_ImageCacheSharedPtr_ctor(ptr: *mut oiio_ImageCache_t, _result: *mut *mut oiio_ImageCacheSharedPtr_t) -> c_int;
 
 pub fn oiio_ImageCache_attribute(_this: *mut oiio_ImageCache_t, name: *const c_char, type_: oiio_TypeDesc_t, data: *const c_void, _result: *mut bool) -> c_int;
@@ -1863,6 +1958,8 @@ pub fn oiio_ImageOutput_open(_this: *mut oiio_ImageOutput_t, filename: *const c_
 
 pub fn oiio_ImageOutput_open_multi_subimage(_this: *mut oiio_ImageOutput_t, filename: *const c_char, num_subimages: c_int, specs: *const oiio_ImageSpec_t, _result: *mut bool) -> c_int;
 
+pub fn oiio_ImageOutputPtr_get(ptr: *mut oiio_ImageOutputPtr_t, _result: *mut *mut oiio_ImageOutput_t) -> c_int;
+
 pub fn oiio_roi_union(A: *const oiio_ROI_t, B: *const oiio_ROI_t, _result: *mut oiio_ROI_t) -> c_int;
 
 pub fn oiio_roi_intersection(A: *const oiio_ROI_t, B: *const oiio_ROI_t, _result: *mut oiio_ROI_t) -> c_int;
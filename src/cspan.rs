@@ -76,15 +76,14 @@ macro_rules! cspan {
     };
 }
 
-/*
 #[cfg(feature = "half")]
 cspan!(
     oiio_CspanF16_t,
     oiio_CspanF16_ctor,
     oiio_CspanF16_dtor,
     CspanF16,
-    f16
-);*/
+    half::f16
+);
 
 cspan!(
     oiio_CspanF32_t,
@@ -1,51 +1,268 @@
-use crate::*;
+use crate::{algorithms::*, *};
 use anyhow::{anyhow, Result};
 
+/// How the alpha channel of an `ImageBuffer` is interpreted when converting it
+/// to an [`egui::ColorImage`].
+///
+/// The alpha channel is the second channel of a two-channel (gray-alpha) and
+/// the fourth channel of a four or more channel image.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum AlphaMode {
+    /// The colors are premultiplied by alpha (*associated* alpha).
+    ///
+    /// This is the convention OIIO uses for pixels in memory.
+    #[default]
+    Premultiplied,
+    /// The colors are not premultiplied by alpha (*unassociated* alpha).
+    Straight,
+    /// The alpha channel is ignored and the result is opaque.
+    Opaque,
+}
+
+/// Optional parameters for [`ImageBuffer`]'s
+/// [`to_color_image_with()`](ImageBuffer::to_color_image_with) method.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ColorImageOptions<'a> {
+    /// The OCIO *display* and *view* to transform the pixels with. An empty
+    /// string selects the default display resp. view of the color
+    /// configuration.
+    ///
+    /// If `None`, the pixels are converted to *sRGB*.
+    pub display_view: Option<(&'a str, &'a str)>,
+    /// Exposure adjustment, in stops, applied to the color channels before
+    /// they are transformed for display.
+    pub exposure: f32,
+    pub alpha_mode: AlphaMode,
+}
+
+/// Optional parameters for [`ImageBuffer`]'s
+/// [`load_texture()`](ImageBuffer::load_texture) method.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LoadTextureOptions<'a> {
+    /// The maximum width and height of the texture. Larger images are
+    /// downsampled (preserving the aspect ratio) before the upload.
+    ///
+    /// If `None`, the maximum texture size of the `egui` backend is used.
+    pub max_size: Option<u32>,
+    pub color_image: ColorImageOptions<'a>,
+    pub texture: egui::TextureOptions,
+}
+
+/// Converts with the default [`ColorImageOptions`], i.e. to *sRGB*.
+impl TryFrom<&ImageBuffer> for egui::ColorImage {
+    type Error = anyhow::Error;
+
+    fn try_from(image_buffer: &ImageBuffer) -> Result<Self> {
+        image_buffer.to_color_image_with(&ColorImageOptions::default())
+    }
+}
+
 impl TryFrom<ImageBuffer> for egui::ColorImage {
     type Error = anyhow::Error;
 
-    fn try_from(mut image_buffer: ImageBuffer) -> Result<Self> {
-        let mut bounds = image_buffer
-            .data_window()
-            .bounds()
-            .ok_or(anyhow!("Image is empty"))?
-            .clone();
+    fn try_from(image_buffer: ImageBuffer) -> Result<Self> {
+        (&image_buffer).try_into()
+    }
+}
+
+/// # Egui
+impl ImageBuffer {
+    /// Convert the [`data_window()`](ImageBuffer::data_window) of this image
+    /// into an [`egui::ColorImage`].
+    ///
+    /// One- and two-channel images are treated as gray and gray-alpha, three
+    /// channels as RGB and four or more channels as RGBA. Channels beyond the
+    /// fourth are ignored.
+    ///
+    /// The result has premultiplied colors in *sRGB* (or the given display)
+    /// encoding, as `egui` expects.
+    ///
+    /// Returns an error if the image is empty.
+    #[named]
+    pub fn to_color_image_with(&self, options: &ColorImageOptions) -> Result<egui::ColorImage> {
+        if !self.is_initialized() || 0 == self.channel_count() || self.data_window().is_empty() {
+            return Err(anyhow!("[fn {}] Image is empty", function_name!()));
+        }
 
+        let mut bounds = self.data_window();
         let dimensions = [bounds.width() as usize, bounds.height() as _];
 
-        let channel_count = bounds.channel_count();
+        let channel_count = self.channel_count().min(4);
+        bounds.set_channel(0..channel_count);
 
-        if 2 < channel_count {
-            // Make sure we're in the expected color space.
-            image_buffer.color_convert(None, "sRGB".into())?;
-        }
+        let alpha_channel_index = match channel_count {
+            2 => Some(1),
+            4 => Some(3),
+            _ => None,
+        };
+
+        let mut pixels: Vec<f32> = self.pixels(&Region::Bounds(bounds.clone()))?;
+
+        let gain = options.exposure.exp2();
+
+        pixels
+            .chunks_exact_mut(channel_count as _)
+            .for_each(|pixel| {
+                let alpha = alpha_channel_index.map_or(1.0, |index| match options.alpha_mode {
+                    AlphaMode::Premultiplied => 1.0,
+                    AlphaMode::Straight => pixel[index],
+                    AlphaMode::Opaque => {
+                        pixel[index] = 1.0;
+                        1.0
+                    }
+                });
+
+                pixel
+                    .iter_mut()
+                    .enumerate()
+                    .filter(|(index, _)| Some(*index) != alpha_channel_index)
+                    // Premultiplying makes all alpha modes behave the same
+                    // during the transform below.
+                    .for_each(|(_, value)| *value *= gain * alpha);
+            });
+
+        // Gray-alpha is expanded to RGBA so the color transforms below
+        // recognize the alpha channel.
+        let (pixels, channel_count) = if 2 == channel_count {
+            (
+                pixels
+                    .chunks_exact(2)
+                    .flat_map(|pixel| [pixel[0], pixel[0], pixel[0], pixel[1]])
+                    .collect(),
+                4,
+            )
+        } else {
+            (pixels, channel_count)
+        };
+
+        // Color transforms unpremultiply before and premultiply after.
+        let mut image_buffer = ImageBuffer::from_slice(
+            dimensions[0] as _,
+            dimensions[1] as _,
+            channel_count as _,
+            BaseType::F32,
+            self.color_space().as_deref(),
+            &pixels,
+        )?;
 
-        match channel_count {
-            // Grayscale image.
-            1 | 2 => {
-                // We assume this is a grayscale image (alpha channel will be
-                // dropped, if present).
-                bounds.set_channel(0..1);
-                let pixels: Vec<u8> = image_buffer.pixels(&Region::Bounds(bounds))?;
-
-                Ok(egui::ColorImage::from_gray(dimensions, &pixels))
-            }
-            // RGB image.
-            3 => {
-                let pixels: Vec<u8> = image_buffer.pixels(&Region::All)?;
-
-                Ok(egui::ColorImage::from_rgb(dimensions, &pixels))
-            }
-            // RGBA image.
-            _ => {
-                // Make sure `pixels()` returns a buffer with max. 4 channels.
-                bounds.set_channel(0..4);
-                let pixels: Vec<u8> = image_buffer.pixels(&Region::Bounds(bounds))?;
-
-                Ok(egui::ColorImage::from_rgba_premultiplied(
-                    dimensions, &pixels,
-                ))
-            }
+        match options.display_view {
+            Some((display, view)) => image_buffer.ocio_display(
+                Some(display).filter(|display| !display.is_empty()),
+                Some(view).filter(|view| !view.is_empty()),
+            )?,
+            None => image_buffer.color_convert(None, "sRGB")?,
+        };
+
+        let pixels: Vec<u8> = image_buffer.pixels(&Region::All)?;
+
+        Ok(match channel_count {
+            1 => egui::ColorImage::from_gray(dimensions, &pixels),
+            3 => egui::ColorImage::from_rgb(dimensions, &pixels),
+            _ => egui::ColorImage::from_rgba_premultiplied(dimensions, &pixels),
+        })
+    }
+
+    /// Convert this image with
+    /// [`to_color_image_with()`](ImageBuffer::to_color_image_with) and upload
+    /// it as a texture via [`egui::Context::load_texture()`].
+    ///
+    /// Images larger than [`max_size`](LoadTextureOptions::max_size) are
+    /// downsampled first. This happens before the display transform, i.e. in
+    /// the color space of the image.
+    pub fn load_texture(
+        &self,
+        context: &egui::Context,
+        name: impl Into<String>,
+        options: &LoadTextureOptions,
+    ) -> Result<egui::TextureHandle> {
+        let max_size = options
+            .max_size
+            .unwrap_or_else(|| context.input(|input| input.max_texture_side) as _);
+
+        if 0 == max_size {
+            return Err(anyhow!("Maximum texture size must be non-zero"));
         }
+
+        let data_window = self.data_window();
+        let (width, height) = (data_window.width(), data_window.height());
+
+        let color_image = if width <= max_size && height <= max_size {
+            self.to_color_image_with(&options.color_image)?
+        } else {
+            let scale = max_size as f32 / width.max(height) as f32;
+            let mut image_buffer = ImageBuffer::new();
+
+            image_buffer.replace_by_resize_with(
+                self,
+                &ResizeOptions {
+                    region: Bounds::new(
+                        0..((width as f32 * scale).round() as i32).max(1),
+                        0..((height as f32 * scale).round() as i32).max(1),
+                        0..1,
+                        Some(0..self.channel_count()),
+                    )
+                    .into(),
+                    ..Default::default()
+                },
+            )?;
+
+            image_buffer.to_color_image_with(&options.color_image)?
+        };
+
+        Ok(context.load_texture(name, color_image, options.texture))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn color_image() -> Result<()> {
+        let image_buffer =
+            ImageBuffer::from_fill(&[0.5, 0.5, 0.5, 0.5], &Bounds::new_2d(0..16, 8..16))?;
+
+        let color_image = image_buffer.to_color_image_with(&ColorImageOptions {
+            exposure: 1.0,
+            alpha_mode: AlphaMode::Straight,
+            ..Default::default()
+        })?;
+
+        assert_eq!([16, 8], color_image.size);
+        // Exposed straight 1.0 at 50% alpha.
+        assert_eq!(
+            egui::Color32::from_rgba_premultiplied(128, 128, 128, 128),
+            color_image.pixels[0]
+        );
+
+        // The alpha of gray-alpha images is not color transformed.
+        let gray_alpha =
+            ImageBuffer::from_fill(&[0.5, 0.5], &Bounds::new(0..16, 8..16, 0..1, Some(0..2)))?;
+        let color_image = gray_alpha.to_color_image_with(&ColorImageOptions {
+            exposure: 1.0,
+            alpha_mode: AlphaMode::Straight,
+            ..Default::default()
+        })?;
+
+        assert_eq!(
+            egui::Color32::from_rgba_premultiplied(128, 128, 128, 128),
+            color_image.pixels[0]
+        );
+
+        let context = egui::Context::default();
+        let texture = image_buffer.load_texture(
+            &context,
+            "color_image",
+            &LoadTextureOptions {
+                max_size: Some(4),
+                ..Default::default()
+            },
+        )?;
+
+        assert_eq!([4, 2], texture.size());
+
+        assert!(egui::ColorImage::try_from(&ImageBuffer::new()).is_err());
+
+        Ok(())
     }
 }
@@ -1,95 +1,94 @@
 use crate::*;
 use anyhow::{anyhow, Result};
+#[cfg(feature = "half")]
+use half::f16;
 
 macro_rules! try_image_buffer_from_image {
-    ($rust_type:ty, $base_type:expr, $channel_count:expr) => {
+    ($rust_type:ty, $base_type:expr) => {
         impl TryFrom<&$rust_type> for ImageBuffer {
             type Error = anyhow::Error;
 
-            fn try_from(image_buffer: &$rust_type) -> Result<ImageBuffer> {
-                let slice = image_buffer.as_raw();
-                let width = image_buffer.width();
-                let height = image_buffer.height();
-                let channel_count = $channel_count;
-
-                let min_size = width as usize * height as usize * channel_count as usize;
-
-                if slice.len() < min_size {
-                    return Err(anyhow!("Slice length must be at least {min_size}"));
-                }
-
-                let mut image_buffer = ImageBuffer::from_dimensions_ffi(
-                    width,
-                    height,
-                    channel_count,
-                    TypeDesc {
-                        base_type: Some($base_type),
-                        ..Default::default()
-                    },
-                    None,
-                );
-
-                image_buffer.set_pixels(slice, &Region::All)?;
-
-                Ok(image_buffer)
+            fn try_from(image: &$rust_type) -> Result<ImageBuffer> {
+                image_buffer_from_image(image, $base_type, None)
             }
         }
 
         impl TryFrom<$rust_type> for ImageBuffer {
             type Error = anyhow::Error;
 
-            fn try_from(image_buffer: $rust_type) -> Result<Self> {
-                (&image_buffer).try_into()
+            fn try_from(image: $rust_type) -> Result<Self> {
+                (&image).try_into()
             }
         }
     };
 }
 
-try_image_buffer_from_image!(image::GrayImage, BaseType::U8, 1);
-try_image_buffer_from_image!(image::GrayAlphaImage, BaseType::U8, 2);
-try_image_buffer_from_image!(image::RgbImage, BaseType::U8, 3);
-try_image_buffer_from_image!(image::RgbaImage, BaseType::U8, 4);
+try_image_buffer_from_image!(image::GrayImage, BaseType::U8);
+try_image_buffer_from_image!(image::GrayAlphaImage, BaseType::U8);
+try_image_buffer_from_image!(image::RgbImage, BaseType::U8);
+try_image_buffer_from_image!(image::RgbaImage, BaseType::U8);
 try_image_buffer_from_image!(
     image::ImageBuffer<image::Luma<u16>, Vec<u16>>,
-    BaseType::U16,
-    1
+    BaseType::U16
 );
 try_image_buffer_from_image!(
     image::ImageBuffer<image::LumaA<u16>, Vec<u16>>,
-    BaseType::U16,
-    2
-);
-try_image_buffer_from_image!(
-    image::ImageBuffer<image::Rgb<u16>, Vec<u16>>,
-    BaseType::U16,
-    3
+    BaseType::U16
 );
+try_image_buffer_from_image!(image::ImageBuffer<image::Rgb<u16>, Vec<u16>>, BaseType::U16);
 try_image_buffer_from_image!(
     image::ImageBuffer<image::Rgba<u16>, Vec<u16>>,
-    BaseType::U16,
-    4
+    BaseType::U16
 );
-try_image_buffer_from_image!(image::Rgb32FImage, BaseType::F32, 3);
-try_image_buffer_from_image!(image::Rgba32FImage, BaseType::F32, 4);
+try_image_buffer_from_image!(image::Rgb32FImage, BaseType::F32);
+try_image_buffer_from_image!(image::Rgba32FImage, BaseType::F32);
+
+macro_rules! image_buffer_f16_from_image {
+    ($fn_name:ident, $rust_type:ty, $channel_count:expr) => {
+        /// Create an `ImageBuffer` with [`BaseType::F16`] pixels from `image`.
+        ///
+        /// The `f32` pixels are narrowed to `f16` on the Rust side so the
+        /// result uses half the memory of the `TryFrom` conversion.
+        pub fn $fn_name(image: &$rust_type) -> Result<Self> {
+            let slice = image
+                .as_raw()
+                .iter()
+                .map(|&value| f16::from_f32(value))
+                .collect::<Vec<_>>();
+
+            let mut image_buffer = ImageBuffer::from_dimensions_ffi(
+                image.width(),
+                image.height(),
+                $channel_count,
+                TypeDesc::F16,
+                None,
+            );
+
+            image_buffer.set_pixels(&slice, &Region::All)?;
+
+            Ok(image_buffer)
+        }
+    };
+}
+
+/// # `f16` Conversion
+///
+/// The `image` crate has no `f16` pixel type. These store an `Rgb32FImage` or
+/// `Rgba32FImage` natively as `f16`. Converting such an `ImageBuffer` back with
+/// `TryFrom` widens its pixels to `f32` again.
+#[cfg(feature = "half")]
+impl ImageBuffer {
+    image_buffer_f16_from_image!(from_rgb32f_image_as_f16, image::Rgb32FImage, 3);
+    image_buffer_f16_from_image!(from_rgba32f_image_as_f16, image::Rgba32FImage, 4);
+}
 
 macro_rules! try_image_from_image_buffer {
-    ($rust_type:ty, $channel_count:expr) => {
+    ($rust_type:ty) => {
         impl TryFrom<&ImageBuffer> for $rust_type {
             type Error = anyhow::Error;
 
             fn try_from(image_buffer: &ImageBuffer) -> Result<Self> {
-                let mut bounds = image_buffer.data_window().clone();
-
-                // Strip superfluous channels from the image and/or fill missing
-                // channels with 0.
-                bounds.set_channel(0..$channel_count);
-
-                image::ImageBuffer::from_vec(
-                    bounds.width(),
-                    bounds.height(),
-                    image_buffer.pixels(&Region::Bounds(bounds))?,
-                )
-                .ok_or(anyhow!("Failed to convert to GrayImage"))
+                image_from_image_buffer(image_buffer)
             }
         }
 
@@ -103,34 +102,25 @@ macro_rules! try_image_from_image_buffer {
     };
 }
 
-try_image_from_image_buffer!(image::GrayImage, 1);
-try_image_from_image_buffer!(image::GrayAlphaImage, 2);
-try_image_from_image_buffer!(image::ImageBuffer<image::Luma<u16>, Vec<u16>>, 1);
-try_image_from_image_buffer!(image::ImageBuffer<image::LumaA<u16>, Vec<u16>>, 2);
-try_image_from_image_buffer!(image::ImageBuffer<image::Rgb<u16>, Vec<u16>>, 3);
-try_image_from_image_buffer!(image::ImageBuffer<image::Rgba<u16>, Vec<u16>>, 4);
-try_image_from_image_buffer!(image::Rgb32FImage, 3);
-try_image_from_image_buffer!(image::Rgba32FImage, 4);
+try_image_from_image_buffer!(image::GrayImage);
+try_image_from_image_buffer!(image::GrayAlphaImage);
+try_image_from_image_buffer!(image::ImageBuffer<image::Luma<u16>, Vec<u16>>);
+try_image_from_image_buffer!(image::ImageBuffer<image::LumaA<u16>, Vec<u16>>);
+try_image_from_image_buffer!(image::ImageBuffer<image::Rgb<u16>, Vec<u16>>);
+try_image_from_image_buffer!(image::ImageBuffer<image::Rgba<u16>, Vec<u16>>);
+try_image_from_image_buffer!(image::Rgb32FImage);
+try_image_from_image_buffer!(image::Rgba32FImage);
 
 impl TryFrom<&ImageBuffer> for image::RgbImage {
     type Error = anyhow::Error;
 
     fn try_from(image_buffer: &ImageBuffer) -> Result<Self> {
-        let mut bounds = image_buffer.data_window().clone();
-
         // Make sure we're in the expected color space.
-        let image_buffer = ImageBuffer::from_color_convert(image_buffer, None, "sRGB")?;
-
-        // Strip superfluous channels from the image and/or fill missing
-        // channels with 0.
-        bounds.set_channel(0..3);
-
-        image::ImageBuffer::from_vec(
-            bounds.width(),
-            bounds.height(),
-            image_buffer.pixels(&Region::Bounds(bounds))?,
-        )
-        .ok_or(anyhow!("Failed to convert to RgbImage"))
+        image_from_image_buffer(&ImageBuffer::from_color_convert(
+            image_buffer,
+            None,
+            "sRGB",
+        )?)
     }
 }
 
@@ -138,21 +128,10 @@ impl TryFrom<ImageBuffer> for image::RgbImage {
     type Error = anyhow::Error;
 
     fn try_from(mut image_buffer: ImageBuffer) -> Result<Self> {
-        let mut bounds = image_buffer.data_window().clone();
-
         // Make sure we're in the expected color space.
         image_buffer.color_convert(None, "sRGB")?;
 
-        // Strip superfluous channels from the image and/or fill missing
-        // channels with 0.
-        bounds.set_channel(0..3);
-
-        image::ImageBuffer::from_vec(
-            bounds.width(),
-            bounds.height(),
-            image_buffer.pixels(&Region::Bounds(bounds))?,
-        )
-        .ok_or(anyhow!("Failed to convert to RgbImage"))
+        image_from_image_buffer(&image_buffer)
     }
 }
 
@@ -160,21 +139,12 @@ impl TryFrom<&ImageBuffer> for image::RgbaImage {
     type Error = anyhow::Error;
 
     fn try_from(image_buffer: &ImageBuffer) -> Result<Self> {
-        let mut bounds = image_buffer.data_window().clone();
-
         // Make sure we're in the expected color space.
-        let image_buffer = ImageBuffer::from_color_convert(image_buffer, None, "sRGB")?;
-
-        // Strip superfluous channels from the image and/or fill missing
-        // channels with 0.
-        bounds.set_channel(0..4);
-
-        image::ImageBuffer::from_vec(
-            bounds.width(),
-            bounds.height(),
-            image_buffer.pixels(&Region::Bounds(bounds))?,
-        )
-        .ok_or(anyhow!("Failed to convert to RgbaImage"))
+        image_from_image_buffer(&ImageBuffer::from_color_convert(
+            image_buffer,
+            None,
+            "sRGB",
+        )?)
     }
 }
 
@@ -182,21 +152,10 @@ impl TryFrom<ImageBuffer> for image::RgbaImage {
     type Error = anyhow::Error;
 
     fn try_from(mut image_buffer: ImageBuffer) -> Result<Self> {
-        let mut bounds = image_buffer.data_window().clone();
-
         // Make sure we're in the expected color space.
         image_buffer.color_convert(None, "sRGB")?;
 
-        // Strip superfluous channels from the image and/or fill missing
-        // channels with 0.
-        bounds.set_channel(0..4);
-
-        image::ImageBuffer::from_vec(
-            bounds.width(),
-            bounds.height(),
-            image_buffer.pixels(&Region::Bounds(bounds))?,
-        )
-        .ok_or(anyhow!("Failed to convert to RgbaImage"))
+        image_from_image_buffer(&image_buffer)
     }
 }
 
@@ -214,20 +173,8 @@ impl TryFrom<&ImageBuffer> for image::DynamicImage {
                     3 => Ok(image::DynamicImage::ImageRgb8(image_buffer.try_into()?)),
                     _ => Ok(image::DynamicImage::ImageRgba8(image_buffer.try_into()?)),
                 },
-                BaseType::U16 => match bounds.channel_count() {
-                    1 => Ok(image::DynamicImage::ImageLuma16(image_buffer.try_into()?)),
-                    2 => Ok(image::DynamicImage::ImageLumaA16(image_buffer.try_into()?)),
-                    3 => Ok(image::DynamicImage::ImageRgb16(image_buffer.try_into()?)),
-                    _ => Ok(image::DynamicImage::ImageRgba16(image_buffer.try_into()?)),
-                },
-                // We promote all other types to RGB(A) `f32`.
-                _ => {
-                    if bounds.channel_count() < 4 {
-                        Ok(image::DynamicImage::ImageRgb32F(image_buffer.try_into()?))
-                    } else {
-                        Ok(image::DynamicImage::ImageRgba32F(image_buffer.try_into()?))
-                    }
-                }
+                // Everything else is converted losslessly.
+                _ => dynamic_image_from_image_buffer(image_buffer),
             }
         } else {
             Err(anyhow!("ImageBuffer has no BaseType"))
@@ -243,10 +190,188 @@ impl TryFrom<ImageBuffer> for image::DynamicImage {
     }
 }
 
+impl TryFrom<&image::DynamicImage> for ImageBuffer {
+    type Error = anyhow::Error;
+
+    fn try_from(image: &image::DynamicImage) -> Result<Self> {
+        ImageBuffer::from_dynamic_image_with(image, None)
+    }
+}
+
+impl TryFrom<image::DynamicImage> for ImageBuffer {
+    type Error = anyhow::Error;
+
+    fn try_from(image: image::DynamicImage) -> Result<Self> {
+        (&image).try_into()
+    }
+}
+
+/// # `image` Conversion With Color Spaces
+///
+/// The `TryFrom` conversions into 8-bit RGB(A) images always convert to
+/// *sRGB*. All other conversions keep the pixel values as they are.
+///
+/// These methods make the color space explicit. All pixel types are converted
+/// losslessly: 8-bit, 16-bit and 32-bit float images map to `ImageBuffer`s
+/// with a [`BaseType`] of `U8`, `U16` and `F32` respectively and vice versa.
+/// Gray and gray-alpha images map to one- and two-channel `ImageBuffer`s.
+impl ImageBuffer {
+    /// Create an `ImageBuffer` from an [`image::DynamicImage`].
+    ///
+    /// The `color_space` is the one the pixels of `image` are in. It is stored
+    /// in the resulting `ImageBuffer`'s [`ImageSpec`]. If `None` no color space
+    /// is set.
+    pub fn from_dynamic_image_with(
+        image: &image::DynamicImage,
+        color_space: Option<&str>,
+    ) -> Result<Self> {
+        use image::DynamicImage::*;
+
+        match image {
+            ImageLuma8(image) => image_buffer_from_image(image, BaseType::U8, color_space),
+            ImageLumaA8(image) => image_buffer_from_image(image, BaseType::U8, color_space),
+            ImageRgb8(image) => image_buffer_from_image(image, BaseType::U8, color_space),
+            ImageRgba8(image) => image_buffer_from_image(image, BaseType::U8, color_space),
+            ImageLuma16(image) => image_buffer_from_image(image, BaseType::U16, color_space),
+            ImageLumaA16(image) => image_buffer_from_image(image, BaseType::U16, color_space),
+            ImageRgb16(image) => image_buffer_from_image(image, BaseType::U16, color_space),
+            ImageRgba16(image) => image_buffer_from_image(image, BaseType::U16, color_space),
+            ImageRgb32F(image) => image_buffer_from_image(image, BaseType::F32, color_space),
+            ImageRgba32F(image) => image_buffer_from_image(image, BaseType::F32, color_space),
+            // `DynamicImage` is non-exhaustive.
+            image => image_buffer_from_image(&image.to_rgba32f(), BaseType::F32, color_space),
+        }
+    }
+
+    /// Convert this `ImageBuffer` into an [`image::DynamicImage`].
+    ///
+    /// If `color_space` is given, the pixels are converted to it first.
+    /// Otherwise they are left as they are.
+    ///
+    /// `U8` and `U16` pixels map to the respective 8- and 16-bit variants. All
+    /// other types are converted to `f32`. One and two channels map to gray
+    /// and gray-alpha, three channels to RGB and four or more channels to
+    /// RGBA.
+    ///
+    /// As `image` has no `f32` gray variants, one and two channel `f32` images
+    /// map to RGB resp. RGBA with the gray value replicated.
+    pub fn to_dynamic_image_with(&self, color_space: Option<&str>) -> Result<image::DynamicImage> {
+        match color_space {
+            Some(color_space) => dynamic_image_from_image_buffer(&ImageBuffer::from_color_convert(
+                self,
+                None,
+                color_space,
+            )?),
+            None => dynamic_image_from_image_buffer(self),
+        }
+    }
+}
+
+fn image_buffer_from_image<P>(
+    image: &image::ImageBuffer<P, Vec<P::Subpixel>>,
+    base_type: BaseType,
+    color_space: Option<&str>,
+) -> Result<ImageBuffer>
+where
+    P: image::Pixel,
+    ImageBuffer: Pixels<P::Subpixel>,
+{
+    let mut image_buffer = ImageBuffer::from_dimensions_ffi(
+        image.width(),
+        image.height(),
+        P::CHANNEL_COUNT as _,
+        TypeDesc {
+            base_type: Some(base_type),
+            ..Default::default()
+        },
+        color_space,
+    );
+
+    image_buffer.set_pixels(image.as_raw(), &Region::All)?;
+
+    Ok(image_buffer)
+}
+
+fn image_from_image_buffer<P>(
+    image_buffer: &ImageBuffer,
+) -> Result<image::ImageBuffer<P, Vec<P::Subpixel>>>
+where
+    P: image::Pixel,
+    ImageBuffer: Pixels<P::Subpixel>,
+{
+    let mut bounds = image_buffer.data_window().clone();
+
+    // Strip superfluous channels from the image and/or fill missing channels
+    // with 0.
+    bounds.set_channel(0..P::CHANNEL_COUNT as _);
+
+    image::ImageBuffer::from_vec(
+        bounds.width(),
+        bounds.height(),
+        image_buffer.pixels(&Region::Bounds(bounds))?,
+    )
+    .ok_or(anyhow!(
+        "Failed to convert to image::ImageBuffer<{}>",
+        std::any::type_name::<P>()
+    ))
+}
+
+/// Converts a one or two channel `image_buffer` to RGB resp. RGBA by
+/// replicating the first channel.
+fn rgb_image_from_gray_image_buffer<P>(
+    image_buffer: &ImageBuffer,
+) -> Result<image::ImageBuffer<P, Vec<f32>>>
+where
+    P: image::Pixel<Subpixel = f32>,
+{
+    let mut bounds = image_buffer.data_window().clone();
+    let channel_count = image_buffer.channel_count();
+    bounds.set_channel(0..channel_count);
+
+    let pixels: Vec<f32> = image_buffer.pixels(&Region::Bounds(bounds.clone()))?;
+
+    image::ImageBuffer::from_vec(
+        bounds.width(),
+        bounds.height(),
+        pixels
+            .chunks_exact(channel_count as _)
+            .flat_map(|pixel| [pixel[0]; 3].into_iter().chain(pixel[1..].iter().copied()))
+            .collect(),
+    )
+    .ok_or(anyhow!(
+        "Failed to convert to image::ImageBuffer<{}>",
+        std::any::type_name::<P>()
+    ))
+}
+
+fn dynamic_image_from_image_buffer(image_buffer: &ImageBuffer) -> Result<image::DynamicImage> {
+    use image::DynamicImage::*;
+
+    let base_type = image_buffer
+        .type_desc()
+        .base_type
+        .ok_or(anyhow!("ImageBuffer has no BaseType"))?;
+
+    Ok(match (base_type, image_buffer.channel_count()) {
+        (BaseType::U8, 1) => ImageLuma8(image_from_image_buffer(image_buffer)?),
+        (BaseType::U8, 2) => ImageLumaA8(image_from_image_buffer(image_buffer)?),
+        (BaseType::U8, 3) => ImageRgb8(image_from_image_buffer(image_buffer)?),
+        (BaseType::U8, _) => ImageRgba8(image_from_image_buffer(image_buffer)?),
+        (BaseType::U16, 1) => ImageLuma16(image_from_image_buffer(image_buffer)?),
+        (BaseType::U16, 2) => ImageLumaA16(image_from_image_buffer(image_buffer)?),
+        (BaseType::U16, 3) => ImageRgb16(image_from_image_buffer(image_buffer)?),
+        (BaseType::U16, _) => ImageRgba16(image_from_image_buffer(image_buffer)?),
+        // We promote all other types to RGB(A) `f32`. There are no `f32` gray
+        // variants so gray is replicated into RGB.
+        (_, 1) => ImageRgb32F(rgb_image_from_gray_image_buffer(image_buffer)?),
+        (_, 2) => ImageRgba32F(rgb_image_from_gray_image_buffer(image_buffer)?),
+        (_, 3) => ImageRgb32F(image_from_image_buffer(image_buffer)?),
+        (_, _) => ImageRgba32F(image_from_image_buffer(image_buffer)?),
+    })
+}
+
 #[cfg(test)]
 mod tests {
-    use std::fs::File;
-
     use crate::*;
 
     #[test]
@@ -266,4 +391,58 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn adapter_lossless() -> Result<()> {
+        let luma_u16 = image::ImageBuffer::<image::Luma<u16>, Vec<u16>>::from_fn(4, 4, |x, y| {
+            image::Luma([(x * 4 + y) as u16 * 4099])
+        });
+
+        let image_buf = ImageBuffer::from_dynamic_image_with(
+            &image::DynamicImage::ImageLuma16(luma_u16.clone()),
+            Some("lin_rec709"),
+        )?;
+
+        assert_eq!(1, image_buf.channel_count());
+        assert_eq!(Some(BaseType::U16), image_buf.type_desc().base_type);
+
+        let dynamic_image = image_buf.to_dynamic_image_with(None)?;
+        assert_eq!(Some(&luma_u16), dynamic_image.as_luma16());
+
+        let rgba_f32 = image::Rgba32FImage::from_pixel(4, 4, image::Rgba([0.1, 2.5, -1.0, 0.5]));
+        let image_buf: ImageBuffer = (&rgba_f32).try_into()?;
+
+        assert_eq!(rgba_f32, image::Rgba32FImage::try_from(&image_buf)?);
+
+        // Float gray has no `image` counterpart and is replicated into RGB.
+        let gray_f32 = ImageBuffer::from_fill(&[0.25], &Bounds::new(0..4, 0..4, 0..1, Some(0..1)))?;
+        let dynamic_image = gray_f32.to_dynamic_image_with(None)?;
+        assert_eq!(
+            Some(&image::Rgb32FImage::from_pixel(4, 4, image::Rgb([0.25; 3]))),
+            dynamic_image.as_rgb32f()
+        );
+
+        let image_buf = ImageBuffer::from_dynamic_image_with(&dynamic_image, None)?;
+        let gray: Vec<f32> = gray_f32.pixels(&Region::All)?;
+        let red: Vec<f32> = image_buf.pixels(&Bounds::new(0..4, 0..4, 0..1, Some(0..1)).into())?;
+        assert_eq!(gray, red);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "half")]
+    #[test]
+    fn adapter_f16() -> Result<()> {
+        let image_buf = ImageBuffer::from_file(Utf8Path::new("assets/j0.3toD__F16_RGBA.exr"))?;
+
+        let rgba_f32_image: image::Rgba32FImage = (&image_buf).try_into()?;
+        let image_buf_f16 = ImageBuffer::from_rgba32f_image_as_f16(&rgba_f32_image)?;
+
+        assert_eq!(Some(BaseType::F16), image_buf_f16.type_desc().base_type);
+        // The source is `f16` already so the round trip is lossless.
+        let pixels: Vec<half::f16> = image_buf.pixels(&Region::All)?;
+        assert_eq!(pixels, image_buf_f16.pixels(&Region::All)?);
+
+        Ok(())
+    }
 }
@@ -3,12 +3,46 @@ use anyhow::{anyhow, Result};
 
 #[cfg(feature = "egui")]
 mod egui;
+#[cfg(feature = "egui")]
+pub use self::egui::*;
 
 #[cfg(feature = "image")]
 mod image;
 
+#[cfg(feature = "ndarray")]
+mod ndarray;
+
 #[cfg(feature = "tiny-skia")]
 mod tiny_skia;
+#[cfg(feature = "tiny-skia")]
+pub use self::tiny_skia::*;
+
+/// Optional parameters for [`ImageBufferFromSlice`]'s
+/// [`from_slice_with()`](ImageBufferFromSlice::from_slice_with) method.
+///
+/// All strides are in bytes. A stride of `None` means the data is tightly
+/// packed along that dimension.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FromSliceOptions {
+    /// The distance between two horizontally adjacent pixels.
+    ///
+    /// For interleaved data this defaults to the size of a pixel. For planar
+    /// data it defaults to the size of a single channel value.
+    pub pixel_stride: Option<usize>,
+    /// The distance between the first pixels of two adjacent rows.
+    ///
+    /// Use this to ingest e.g. GPU readbacks whose rows are padded to some
+    /// alignment. Defaults to `width` times the pixel stride.
+    pub row_stride: Option<usize>,
+    /// If the data is planar (i.e. all values of the first channel, followed
+    /// by all values of the second channel etc.) this is the distance between
+    /// the first values of two adjacent channel planes.
+    ///
+    /// `None` means the channels are interleaved.
+    pub plane_stride: Option<usize>,
+    /// The origin of the resulting [`data_window()`](ImageBuffer::data_window).
+    pub origin: (i32, i32),
+}
 
 pub trait ImageBufferFromSlice<T> {
     fn from_slice(
@@ -19,22 +53,175 @@ pub trait ImageBufferFromSlice<T> {
         color_space: Option<&str>,
         slice: &[T],
     ) -> Result<ImageBuffer>;
+
+    /// Like [`from_slice()`](ImageBufferFromSlice::from_slice) but for data
+    /// that is not tightly packed, planar or that should not start at the
+    /// origin.
+    ///
+    /// The pixels are read directly from `slice` using the given strides. No
+    /// intermediate repacked copy is made.
+    fn from_slice_with(
+        width: u32,
+        height: u32,
+        channels: u16,
+        base_type: BaseType,
+        color_space: Option<&str>,
+        slice: &[T],
+        options: &FromSliceOptions,
+    ) -> Result<ImageBuffer>;
 }
 
-impl ImageBufferFromSlice<u8> for ImageBuffer {
+macro_rules! image_buffer_from_slice {
+    ($rust_type:ty, $cspan_type:ty, $slice_base_type:expr, $fn_name:ident) => {
+        impl ImageBufferFromSlice<$rust_type> for ImageBuffer {
+            #[named]
+            fn from_slice(
+                width: u32,
+                height: u32,
+                channel_count: u16,
+                base_type: BaseType,
+                color_space: Option<&str>,
+                slice: &[$rust_type],
+            ) -> Result<Self> {
+                let min_size = width as usize * height as usize * channel_count as usize;
+
+                if slice.len() < min_size {
+                    return Err(anyhow!("Slice length must be at least {min_size}"));
+                }
+
+                let mut image_buffer = ImageBuffer::from_dimensions_ffi(
+                    width,
+                    height,
+                    channel_count,
+                    TypeDesc {
+                        base_type: Some(base_type),
+                        ..Default::default()
+                    },
+                    color_space,
+                );
+
+                let mut is_ok = std::mem::MaybeUninit::<bool>::uninit();
+
+                unsafe {
+                    $fn_name(
+                        image_buffer.as_raw_ptr_mut(),
+                        ALL.clone().into(),
+                        <$cspan_type>::new(slice).as_raw_ptr() as *const _ as _,
+                        &raw mut is_ok as _,
+                    );
+
+                    let is_ok = is_ok.assume_init();
+
+                    image_buffer.self_or_error(is_ok, function_name!())
+                }
+            }
+
+            fn from_slice_with(
+                width: u32,
+                height: u32,
+                channel_count: u16,
+                base_type: BaseType,
+                color_space: Option<&str>,
+                slice: &[$rust_type],
+                options: &FromSliceOptions,
+            ) -> Result<Self> {
+                ImageBuffer::from_strided_ffi(
+                    width,
+                    height,
+                    channel_count,
+                    base_type,
+                    color_space,
+                    slice,
+                    $slice_base_type,
+                    options,
+                )
+            }
+        }
+    };
+}
+
+image_buffer_from_slice!(
+    u8,
+    CspanU8,
+    oiio_BASETYPE::oiio_BASETYPE_UINT8,
+    oiio_ImageBuf_set_pixels_u8
+);
+image_buffer_from_slice!(
+    u16,
+    CspanU16,
+    oiio_BASETYPE::oiio_BASETYPE_UINT16,
+    oiio_ImageBuf_set_pixels_u16
+);
+image_buffer_from_slice!(
+    u32,
+    CspanU32,
+    oiio_BASETYPE::oiio_BASETYPE_UINT32,
+    oiio_ImageBuf_set_pixels_u32
+);
+#[cfg(feature = "half")]
+image_buffer_from_slice!(
+    half::f16,
+    CspanF16,
+    oiio_BASETYPE::oiio_BASETYPE_HALF,
+    oiio_ImageBuf_set_pixels_f16
+);
+image_buffer_from_slice!(
+    f32,
+    CspanF32,
+    oiio_BASETYPE::oiio_BASETYPE_FLOAT,
+    oiio_ImageBuf_set_pixels_f32
+);
+image_buffer_from_slice!(
+    f64,
+    CspanF64,
+    oiio_BASETYPE::oiio_BASETYPE_DOUBLE,
+    oiio_ImageBuf_set_pixels_f64
+);
+
+impl ImageBuffer {
+    #[allow(clippy::too_many_arguments)]
     #[named]
-    fn from_slice(
+    fn from_strided_ffi<T>(
         width: u32,
         height: u32,
         channel_count: u16,
         base_type: BaseType,
         color_space: Option<&str>,
-        slice: &[u8],
+        slice: &[T],
+        slice_base_type: oiio_BASETYPE,
+        options: &FromSliceOptions,
     ) -> Result<Self> {
-        let min_size = width as usize * height as usize * channel_count as usize;
+        if 0 == width || 0 == height || 0 == channel_count {
+            return Err(anyhow!(
+                "[fn {}] Width, height and channel count must be non-zero",
+                function_name!()
+            ));
+        }
+
+        let value_size = size_of::<T>();
+
+        // Channels are always adjacent for interleaved data. For planar data
+        // each channel is set separately and only ever sees a single value per
+        // pixel.
+        let (pixel_size, channel_step, planes) = match options.plane_stride {
+            None => (value_size * channel_count as usize, value_size, 1),
+            Some(plane_stride) => (value_size, plane_stride, channel_count as u32),
+        };
 
-        if slice.len() < min_size {
-            return Err(anyhow!("Slice length must be at least {min_size}"));
+        let pixel_stride = options.pixel_stride.unwrap_or(pixel_size);
+        let row_stride = options.row_stride.unwrap_or(width as usize * pixel_stride);
+
+        // Offset of the last byte read, plus one.
+        let end = (height as usize - 1) * row_stride
+            + (width as usize - 1) * pixel_stride
+            + (channel_count as usize - 1) * channel_step
+            + value_size;
+
+        if size_of_val(slice) < end {
+            return Err(anyhow!(
+                "[fn {}] Slice must be at least {end} bytes long",
+                function_name!()
+            ));
         }
 
         let mut image_buffer = ImageBuffer::from_dimensions_ffi(
@@ -48,19 +235,97 @@ impl ImageBufferFromSlice<u8> for ImageBuffer {
             color_space,
         );
 
-        let mut is_ok = std::mem::MaybeUninit::<bool>::uninit();
+        let mut is_ok = true;
+
+        for plane in 0..planes {
+            let (channels, offset) = if 1 == planes {
+                (0..channel_count as u32, 0)
+            } else {
+                (plane..plane + 1, plane as usize * channel_step)
+            };
 
-        unsafe {
-            oiio_ImageBuf_set_pixels_u8(
-                image_buffer.as_raw_ptr_mut(),
-                ALL.clone().into(),
-                CspanU8::new(slice).as_raw_ptr() as *const _ as _,
-                &raw mut is_ok as _,
-            );
+            let mut is_plane_ok = std::mem::MaybeUninit::<bool>::uninit();
 
-            let is_ok = is_ok.assume_init();
+            unsafe {
+                oiio_ImageBuf_set_pixels_strided(
+                    image_buffer.as_raw_ptr_mut(),
+                    Bounds::new(0..width as _, 0..height as _, 0..1, Some(channels)).into(),
+                    slice_base_type,
+                    slice.as_ptr().byte_add(offset) as _,
+                    pixel_stride as _,
+                    row_stride as _,
+                    (height as usize * row_stride) as _,
+                    &raw mut is_plane_ok as _,
+                );
 
-            image_buffer.self_or_error(is_ok, function_name!())
+                is_ok &= is_plane_ok.assume_init();
+            }
         }
+
+        let (x, y) = options.origin;
+        image_buffer.set_data_window_origin(x, y, None);
+
+        image_buffer.self_or_error(is_ok, function_name!())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn from_slice_with() -> Result<()> {
+        // A 2×2 RGB image with rows padded to 8 `u16`s.
+        let padded: [u16; 16] = [
+            1, 2, 3, 4, 5, 6, 0, 0, //
+            7, 8, 9, 10, 11, 12, 0, 0,
+        ];
+
+        let image_buffer = ImageBuffer::from_slice_with(
+            2,
+            2,
+            3,
+            BaseType::U16,
+            None,
+            &padded,
+            &FromSliceOptions {
+                row_stride: Some(8 * size_of::<u16>()),
+                origin: (10, 20),
+                ..Default::default()
+            },
+        )?;
+
+        assert_eq!(10, image_buffer.data_window().x_start());
+        assert_eq!(
+            vec![1u16, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12],
+            Pixels::<u16>::pixels(&image_buffer, &Region::All)?
+        );
+
+        // The same image as planar `f32` data.
+        let planar: [f32; 12] = [
+            1.0, 4.0, 7.0, 10.0, // R
+            2.0, 5.0, 8.0, 11.0, // G
+            3.0, 6.0, 9.0, 12.0, // B
+        ];
+
+        let image_buffer = ImageBuffer::from_slice_with(
+            2,
+            2,
+            3,
+            BaseType::F32,
+            None,
+            &planar,
+            &FromSliceOptions {
+                plane_stride: Some(4 * size_of::<f32>()),
+                ..Default::default()
+            },
+        )?;
+
+        assert_eq!(
+            vec![1.0f32, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0],
+            Pixels::<f32>::pixels(&image_buffer, &Region::All)?
+        );
+
+        Ok(())
     }
 }
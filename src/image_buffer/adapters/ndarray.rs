@@ -0,0 +1,242 @@
+use crate::*;
+use anyhow::{anyhow, Result};
+use core::mem::MaybeUninit;
+use ndarray::{Array3, ArrayView3, ArrayViewMut3, ShapeBuilder};
+
+/// # `ndarray`
+///
+/// All arrays are indexed as `(y, x, channel)` and cover the
+/// [`data_window()`](ImageBuffer::data_window) of the image, i.e. index
+/// `(0, 0, 0)` is the first channel of the top left pixel of the data window.
+///
+/// Only 2D images (with a depth of one) are supported.
+impl ImageBuffer {
+    /// Return a zero-copy view of the pixels.
+    ///
+    /// This requires the pixels to be held in
+    /// [`LocalBuffer`](ImageBufferStorage::LocalBuffer) storage in the
+    /// [`BaseType`] corresponding to `T`. Use
+    /// [`to_array()`](ImageBuffer::to_array) otherwise.
+    pub fn as_array_view<T: PixelData>(&self) -> Result<ArrayView3<'_, T>> {
+        let (shape, strides) = self.array_layout::<T>()?;
+        let mut pixels = MaybeUninit::<*const core::ffi::c_void>::uninit();
+
+        Ok(unsafe {
+            oiio_ImageBuf_localpixels_01(self.ptr, &raw mut pixels as _);
+
+            ArrayView3::from_shape_ptr(shape.strides(strides), pixels.assume_init() as *const T)
+        })
+    }
+
+    /// Return a zero-copy mutable view of the pixels.
+    ///
+    /// See [`as_array_view()`](ImageBuffer::as_array_view) for the
+    /// requirements.
+    pub fn as_array_view_mut<T: PixelData>(&mut self) -> Result<ArrayViewMut3<'_, T>> {
+        let (shape, strides) = self.array_layout::<T>()?;
+        let mut pixels = MaybeUninit::<*mut core::ffi::c_void>::uninit();
+
+        Ok(unsafe {
+            oiio_ImageBuf_localpixels_00(self.ptr, &raw mut pixels as _);
+
+            ArrayViewMut3::from_shape_ptr(shape.strides(strides), pixels.assume_init() as *mut T)
+        })
+    }
+
+    /// Return a copy of the pixels, converted to `T`.
+    ///
+    /// This works for any storage, including images backed by an
+    /// [`ImageCache`].
+    pub fn to_array<T: PixelData>(&self) -> Result<Array3<T>>
+    where
+        ImageBuffer: Pixels<T>,
+    {
+        let bounds = self.data_window();
+
+        Ok(Array3::from_shape_vec(
+            (
+                bounds.height() as _,
+                bounds.width() as _,
+                bounds.channel_count() as _,
+            ),
+            self.pixels(&Region::All)?,
+        )?)
+    }
+}
+
+impl ImageBuffer {
+    #[named]
+    fn array_layout<T: PixelData>(&self) -> Result<((usize, usize, usize), (usize, usize, usize))> {
+        if ImageBufferStorage::LocalBuffer != self.storage() {
+            return Err(anyhow!(
+                "[fn {}] Pixels are not held in a local buffer",
+                function_name!()
+            ));
+        }
+
+        if Some(T::BASE_TYPE) != self.type_desc().base_type {
+            return Err(anyhow!(
+                "[fn {}] Pixels are not stored as {:?}",
+                function_name!(),
+                T::BASE_TYPE
+            ));
+        }
+
+        let bounds = self.data_window();
+
+        if 1 != bounds.depth() {
+            return Err(anyhow!("[fn {}] Image is not 2D", function_name!()));
+        }
+
+        let mut pixel_stride = MaybeUninit::<i64>::uninit();
+        let mut row_stride = MaybeUninit::<i64>::uninit();
+
+        let (pixel_stride, row_stride) = unsafe {
+            oiio_ImageBuf_pixel_stride(self.ptr, &raw mut pixel_stride as _);
+            oiio_ImageBuf_scanline_stride(self.ptr, &raw mut row_stride as _);
+
+            (pixel_stride.assume_init(), row_stride.assume_init())
+        };
+
+        let value_size = size_of::<T>() as i64;
+
+        if 0 != pixel_stride % value_size || 0 != row_stride % value_size {
+            return Err(anyhow!(
+                "[fn {}] Strides are not a multiple of the value size",
+                function_name!()
+            ));
+        }
+
+        Ok((
+            (
+                bounds.height() as _,
+                bounds.width() as _,
+                bounds.channel_count() as _,
+            ),
+            (
+                (row_stride / value_size) as _,
+                (pixel_stride / value_size) as _,
+                1,
+            ),
+        ))
+    }
+}
+
+/// Creates an `ImageBuffer` with the [`BaseType`] corresponding to `T`.
+///
+/// Any layout with non-negative strides (e.g. a slice of a larger array or a
+/// view with permuted axes holding planar data) is read directly, without an
+/// intermediate copy.
+impl<T: PixelData> TryFrom<ArrayView3<'_, T>> for ImageBuffer
+where
+    ImageBuffer: ImageBufferFromSlice<T>,
+{
+    type Error = anyhow::Error;
+
+    fn try_from(array: ArrayView3<'_, T>) -> Result<Self> {
+        let (height, width, channel_count) = array.dim();
+
+        // The pointer of an empty array may dangle.
+        if array.is_empty() {
+            return Err(anyhow!("Array is empty"));
+        }
+
+        if array.strides().iter().any(|&stride| stride < 0) {
+            return ImageBuffer::try_from(array.as_standard_layout().view());
+        }
+
+        let [row_stride, pixel_stride, channel_stride] =
+            [0, 1, 2].map(|axis| array.stride_of(ndarray::Axis(axis)) as usize);
+
+        // The memory from the first to the last element of the view.
+        let len = (height - 1) * row_stride
+            + (width - 1) * pixel_stride
+            + (channel_count - 1) * channel_stride
+            + 1;
+        let slice = unsafe { core::slice::from_raw_parts(array.as_ptr(), len) };
+
+        let value_size = size_of::<T>();
+
+        ImageBuffer::from_slice_with(
+            width as _,
+            height as _,
+            channel_count as _,
+            T::BASE_TYPE,
+            None,
+            slice,
+            &FromSliceOptions {
+                pixel_stride: Some(pixel_stride * value_size),
+                row_stride: Some(row_stride * value_size),
+                plane_stride: (1 != channel_stride).then_some(channel_stride * value_size),
+                ..Default::default()
+            },
+        )
+    }
+}
+
+impl<T: PixelData> TryFrom<&Array3<T>> for ImageBuffer
+where
+    ImageBuffer: ImageBufferFromSlice<T>,
+{
+    type Error = anyhow::Error;
+
+    fn try_from(array: &Array3<T>) -> Result<Self> {
+        array.view().try_into()
+    }
+}
+
+impl<T: PixelData> TryFrom<Array3<T>> for ImageBuffer
+where
+    ImageBuffer: ImageBufferFromSlice<T>,
+{
+    type Error = anyhow::Error;
+
+    fn try_from(array: Array3<T>) -> Result<Self> {
+        (&array).try_into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use ndarray::{s, Array3};
+
+    #[test]
+    fn array() -> Result<()> {
+        let array = Array3::<f32>::from_shape_fn((8, 16, 3), |(y, x, channel)| {
+            (y * 100 + x * 10 + channel) as f32
+        });
+
+        let mut image_buffer = ImageBuffer::try_from(&array)?;
+        assert_eq!(array, image_buffer.to_array::<f32>()?);
+
+        // Zero-copy view.
+        assert_eq!(array.view(), image_buffer.as_array_view::<f32>()?);
+        assert!(image_buffer.as_array_view::<u8>().is_err());
+
+        image_buffer.as_array_view_mut::<f32>()?[(1, 2, 0)] = -1.0;
+        assert_eq!(-1.0, image_buffer.to_array::<f32>()?[(1, 2, 0)]);
+
+        // Planar data: channels are the outermost axis in memory.
+        let planar = array
+            .clone()
+            .permuted_axes([2, 0, 1])
+            .as_standard_layout()
+            .into_owned();
+        let image_buffer = ImageBuffer::try_from(planar.view().permuted_axes([1, 2, 0]))?;
+        assert_eq!(array, image_buffer.to_array::<f32>()?);
+
+        // A strided slice.
+        let image_buffer = ImageBuffer::try_from(array.slice(s![1..;2, ..;3, ..]))?;
+        assert_eq!(
+            array.slice(s![1..;2, ..;3, ..]),
+            image_buffer.to_array::<f32>()?
+        );
+
+        // Empty arrays have no pixels to point to.
+        assert!(ImageBuffer::try_from(Array3::<f32>::zeros((0, 16, 3))).is_err());
+        assert!(ImageBuffer::try_from(array.slice(s![.., ..0, ..])).is_err());
+
+        Ok(())
+    }
+}
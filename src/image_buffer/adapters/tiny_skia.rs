@@ -1,10 +1,17 @@
 use crate::*;
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 
-impl TryFrom<tiny_skia::Pixmap> for ImageBuffer {
+/// The pixels of a [`tiny_skia::Pixmap`] are RGBA8 in *sRGB* with the color
+/// premultiplied by alpha in that (non-linear) encoding.
+///
+/// This is the same convention OIIO uses for 8-bit images. The resulting
+/// `ImageBuffer` is therefore a `U8` buffer tagged as *sRGB*. Use
+/// [`from_pixmap_with()`](ImageBuffer::from_pixmap_with) to get linear float
+/// data.
+impl TryFrom<&tiny_skia::Pixmap> for ImageBuffer {
     type Error = anyhow::Error;
 
-    fn try_from(pix_map: tiny_skia::Pixmap) -> Result<Self> {
+    fn try_from(pix_map: &tiny_skia::Pixmap) -> Result<Self> {
         let image_buffer = ImageBuffer::from_slice(
             pix_map.width(),
             pix_map.height(),
@@ -17,3 +24,309 @@ impl TryFrom<tiny_skia::Pixmap> for ImageBuffer {
         Ok(image_buffer)
     }
 }
+
+impl TryFrom<tiny_skia::Pixmap> for ImageBuffer {
+    type Error = anyhow::Error;
+
+    fn try_from(pix_map: tiny_skia::Pixmap) -> Result<Self> {
+        (&pix_map).try_into()
+    }
+}
+
+/// The pixels of the [`data_window()`](ImageBuffer::data_window) are converted
+/// to *sRGB* (unpremultiplying before and premultiplying after the conversion)
+/// and quantized to 8 bits.
+///
+/// One- and two-channel images are treated as gray and gray-alpha. Images
+/// without an alpha channel become opaque.
+impl TryFrom<&ImageBuffer> for tiny_skia::Pixmap {
+    type Error = anyhow::Error;
+
+    fn try_from(image_buffer: &ImageBuffer) -> Result<Self> {
+        let mut bounds = image_buffer.data_window();
+        let channel_count = image_buffer.channel_count().min(4);
+        bounds.set_channel(0..channel_count);
+
+        // Make sure we're in the expected color space.
+        let image_buffer = ImageBuffer::from_color_convert(image_buffer, None, "sRGB")?;
+        let pixels: Vec<u8> = image_buffer.pixels(&Region::Bounds(bounds.clone()))?;
+
+        let data = pixels
+            .chunks_exact(channel_count as _)
+            .flat_map(|pixel| {
+                let [red, green, blue, alpha] = match *pixel {
+                    [gray] => [gray, gray, gray, u8::MAX],
+                    [gray, alpha] => [gray, gray, gray, alpha],
+                    [red, green, blue] => [red, green, blue, u8::MAX],
+                    [red, green, blue, alpha, ..] => [red, green, blue, alpha],
+                    [] => unreachable!(),
+                };
+
+                // tiny-skia requires premultiplied colors to never exceed
+                // alpha. Rounding and e.g. additive (emissive) pixels in HDR
+                // images do not guarantee this.
+                [red.min(alpha), green.min(alpha), blue.min(alpha), alpha]
+            })
+            .collect();
+
+        tiny_skia::Pixmap::from_vec(
+            data,
+            tiny_skia::IntSize::from_wh(bounds.width(), bounds.height())
+                .ok_or(anyhow!("Image is empty"))?,
+        )
+        .ok_or(anyhow!("Failed to convert to Pixmap"))
+    }
+}
+
+impl TryFrom<ImageBuffer> for tiny_skia::Pixmap {
+    type Error = anyhow::Error;
+
+    fn try_from(image_buffer: ImageBuffer) -> Result<Self> {
+        (&image_buffer).try_into()
+    }
+}
+
+/// Optional parameters for [`ImageBuffer`]'s
+/// [`render_pixmap_with()`](ImageBuffer::render_pixmap_with) method.
+#[derive(Clone, Default)]
+pub struct RenderPixmapOptions<'a> {
+    /// The color space of the pixels of the image drawn into.
+    ///
+    /// Defaults to the image's [`color_space()`](ImageBuffer::color_space) or
+    /// `"scene_linear"` if it has none.
+    pub color_space: Option<&'a str>,
+    /// The region that is drawn into. The origin of the [`tiny_skia::PixmapMut`]
+    /// is the top left corner of the region.
+    ///
+    /// See the [Region of Interest](module@algorithms#region-of-interest)
+    /// section in the [module@algorithms] module.
+    pub region: Region,
+}
+
+/// # Tiny Skia
+impl ImageBuffer {
+    /// Create an `ImageBuffer` with `F32` pixels from a [`tiny_skia::Pixmap`].
+    ///
+    /// The pixels are converted from *sRGB* to `color_space`. They are
+    /// unpremultiplied before and premultiplied after the conversion so the
+    /// result has colors premultiplied in `color_space`. If `color_space` is
+    /// `None` the pixels stay in *sRGB*.
+    pub fn from_pixmap_with(
+        pix_map: &tiny_skia::Pixmap,
+        color_space: Option<&str>,
+    ) -> Result<Self> {
+        let image_buffer = ImageBuffer::from_slice(
+            pix_map.width(),
+            pix_map.height(),
+            4,
+            BaseType::F32,
+            Some("sRGB"),
+            pix_map.data(),
+        )?;
+
+        match color_space {
+            Some(color_space) => {
+                ImageBuffer::from_color_convert(&image_buffer, Some("sRGB"), color_space)
+            }
+            None => Ok(image_buffer),
+        }
+    }
+
+    /// Draw into this image with tiny-skia.
+    ///
+    /// The `draw` closure is called with a transparent [`tiny_skia::PixmapMut`]
+    /// the size of the [`data_window()`](ImageBuffer::data_window). Whatever
+    /// is drawn is converted to the color space of the image and composited
+    /// *over* it.
+    ///
+    /// Pixels that are not drawn to keep their values; no precision is lost
+    /// for them even if the image is e.g. HDR float data.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// image_buffer.render_pixmap(|pix_map| {
+    ///     let mut paint = tiny_skia::Paint::default();
+    ///     paint.set_color_rgba8(255, 0, 0, 255);
+    ///
+    ///     pix_map.fill_rect(
+    ///         tiny_skia::Rect::from_xywh(8.0, 8.0, 32.0, 32.0).unwrap(),
+    ///         &paint,
+    ///         tiny_skia::Transform::identity(),
+    ///         None,
+    ///     );
+    /// })?;
+    /// ```
+    pub fn render_pixmap(
+        &mut self,
+        draw: impl FnOnce(&mut tiny_skia::PixmapMut),
+    ) -> Result<&mut Self> {
+        self.render_pixmap_impl(draw, &RenderPixmapOptions::default())?;
+
+        Ok(self)
+    }
+
+    /// Like [`render_pixmap()`](ImageBuffer::render_pixmap) but draws into
+    /// `options.region` and can override the color space the drawing is
+    /// converted to.
+    pub fn render_pixmap_with(
+        &mut self,
+        draw: impl FnOnce(&mut tiny_skia::PixmapMut),
+        options: &RenderPixmapOptions,
+    ) -> Result<&mut Self> {
+        self.render_pixmap_impl(draw, options)?;
+
+        Ok(self)
+    }
+}
+
+impl ImageBuffer {
+    #[named]
+    fn render_pixmap_impl(
+        &mut self,
+        draw: impl FnOnce(&mut tiny_skia::PixmapMut),
+        options: &RenderPixmapOptions,
+    ) -> Result<()> {
+        let channel_count = self.channel_count();
+
+        if 0 == channel_count {
+            return Err(anyhow!("[fn {}] Image has no channels", function_name!()));
+        }
+
+        let bounds = match &options.region {
+            Region::All => self.data_window(),
+            Region::Bounds(bounds) => bounds.clone(),
+        };
+
+        let mut pix_map = tiny_skia::Pixmap::new(bounds.width(), bounds.height())
+            .ok_or_else(|| anyhow!("[fn {}] Region is empty", function_name!()))?;
+
+        draw(&mut pix_map.as_mut());
+
+        let color_space = options
+            .color_space
+            .map(String::from)
+            .or_else(|| self.color_space())
+            .unwrap_or_else(|| "scene_linear".into());
+
+        // Premultiplied RGBA in the color space of this image.
+        let overlay: Vec<f32> =
+            ImageBuffer::from_pixmap_with(&pix_map, Some(&color_space))?.pixels(&Region::All)?;
+
+        let alpha_channel_index = self.image_spec().alpha_channel_index;
+        let width = bounds.width() as usize;
+        let z = bounds.z().start;
+
+        // Only pixels that were drawn to are read and written back.
+        overlay
+            .chunks_exact(4)
+            .enumerate()
+            .filter(|(_, overlay)| 0.0 < overlay[3])
+            .for_each(|(index, overlay)| {
+                let x = bounds.x().start + (index % width) as i32;
+                let y = bounds.y().start + (index / width) as i32;
+
+                let mut pixel = self.get_pixel(x, y, Some(z), WrapMode::Black);
+                let alpha = overlay[3];
+                let mut color_channel = 0;
+
+                pixel.iter_mut().enumerate().for_each(|(channel, value)| {
+                    if Some(channel as u32) == alpha_channel_index {
+                        *value = alpha + *value * (1.0 - alpha);
+                    } else if color_channel < 3 {
+                        *value = overlay[color_channel] + *value * (1.0 - alpha);
+                        color_channel += 1;
+                    }
+                });
+
+                self.set_pixel(x, y, Some(z), &pixel);
+            });
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn pix_map() -> Result<()> {
+        let mut image_buffer =
+            ImageBuffer::from_fill(&[0.5, 0.5, 0.5, 1.0], &Bounds::new_2d(0..32, 0..32))?;
+
+        let pix_map = tiny_skia::Pixmap::try_from(&image_buffer)?;
+        // Linear 0.5 is 188 in sRGB.
+        assert_eq!(188, pix_map.pixel(0, 0).unwrap().red());
+
+        image_buffer.render_pixmap(|pix_map| {
+            let mut paint = tiny_skia::Paint::default();
+            paint.set_color_rgba8(255, 255, 255, 255);
+
+            pix_map.fill_rect(
+                tiny_skia::Rect::from_xywh(8.0, 8.0, 8.0, 8.0).unwrap(),
+                &paint,
+                tiny_skia::Transform::identity(),
+                None,
+            );
+        })?;
+
+        let pixels: Vec<f32> = image_buffer.pixels(&Region::All)?;
+        let pixel = |x: usize, y: usize| &pixels[(y * 32 + x) * 4..][..4];
+
+        assert!(pixel(12, 12)
+            .iter()
+            .all(|value| (1.0 - value).abs() < 1.0e-4));
+        // Untouched.
+        assert_eq!(&[0.5, 0.5, 0.5, 1.0], pixel(0, 0));
+
+        // Semi-transparent red over linear gray. Premultiplied sRGB red with
+        // an alpha of 128 is 1.0 in linear, i.e. `128 / 255` premultiplied.
+        image_buffer.render_pixmap(|pix_map| {
+            let mut paint = tiny_skia::Paint::default();
+            paint.set_color_rgba8(255, 0, 0, 128);
+
+            pix_map.fill_rect(
+                tiny_skia::Rect::from_xywh(16.0, 16.0, 8.0, 8.0).unwrap(),
+                &paint,
+                tiny_skia::Transform::identity(),
+                None,
+            );
+        })?;
+
+        let alpha = 128.0 / 255.0;
+        let pixel = image_buffer.get_pixel(20, 20, None, WrapMode::Black);
+        [
+            alpha + 0.5 * (1.0 - alpha),
+            0.5 * (1.0 - alpha),
+            0.5 * (1.0 - alpha),
+            1.0,
+        ]
+        .iter()
+        .zip(&pixel)
+        .for_each(|(expected, value)| assert!((expected - value).abs() < 1.0e-3));
+
+        // Unpremultiply, convert from sRGB, premultiply.
+        let mut pix_map = tiny_skia::Pixmap::new(1, 1).unwrap();
+        pix_map.pixels_mut()[0] =
+            tiny_skia::PremultipliedColorU8::from_rgba(64, 0, 0, 128).unwrap();
+
+        let image_buffer = ImageBuffer::from_pixmap_with(&pix_map, Some("lin_rec709"))?;
+        let pixel = image_buffer.get_pixel(0, 0, None, WrapMode::Black);
+        let linear = ((0.5f32 + 0.055) / 1.055).powf(2.4);
+        assert!((linear * alpha - pixel[0]).abs() < 1.0e-3);
+        assert!((alpha - pixel[3]).abs() < 1.0e-6);
+
+        assert!(ImageBuffer::new()
+            .render_pixmap_with(
+                |_| {},
+                &RenderPixmapOptions {
+                    region: Bounds::new_2d(0..4, 0..4).into(),
+                    ..Default::default()
+                },
+            )
+            .is_err());
+
+        Ok(())
+    }
+}
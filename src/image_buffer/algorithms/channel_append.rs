@@ -0,0 +1,105 @@
+use crate::{algorithms::*, *};
+
+/// # Channel Append
+///
+/// Append the channels of `b` after the channels of `a`.
+///
+/// The result has the channels of `a` followed by the channels of `b`. If both
+/// images have a channel of the same name, the channel of `b` is renamed by
+/// prefixing it with the name of its subimage (or a unique name if it has
+/// none).
+///
+/// Both images should cover the same pixel data window. If they do not, the
+/// result covers the union of both data windows and pixels only present in
+/// one of the images are zero in the channels of the other.
+///
+/// This is useful to assemble AOVs or other passes rendered to separate files
+/// into a single multichannel image.
+impl ImageBuffer {
+    #[named]
+    pub fn replace_by_channel_append(
+        &mut self,
+        a: &ImageBuffer,
+        b: &ImageBuffer,
+    ) -> Result<&mut Self> {
+        let is_ok = self.channel_append_ffi(a, b, &Options::default());
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+
+    #[named]
+    pub fn replace_by_channel_append_with(
+        &mut self,
+        a: &ImageBuffer,
+        b: &ImageBuffer,
+        options: &Options,
+    ) -> Result<&mut Self> {
+        let is_ok = self.channel_append_ffi(a, b, options);
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+
+    /// Append the channels of `other` after the channels of this image.
+    #[named]
+    pub fn channel_append(&mut self, other: &ImageBuffer) -> Result<&mut Self> {
+        let mut image_buffer = ImageBuffer::new();
+        let is_ok = image_buffer.channel_append_ffi(self, other, &Options::default());
+        *self = image_buffer;
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+
+    /// Append the channels of `other` after the channels of this image.
+    #[named]
+    pub fn channel_append_with(
+        &mut self,
+        other: &ImageBuffer,
+        options: &Options,
+    ) -> Result<&mut Self> {
+        let mut image_buffer = ImageBuffer::new();
+        let is_ok = image_buffer.channel_append_ffi(self, other, options);
+        *self = image_buffer;
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+}
+
+impl ImageBuffer {
+    #[inline]
+    fn channel_append_ffi(&mut self, a: &ImageBuffer, b: &ImageBuffer, options: &Options) -> bool {
+        let mut is_ok = MaybeUninit::<bool>::uninit();
+
+        unsafe {
+            oiio_ImageBufAlgo_channel_append(
+                self.as_raw_ptr_mut(),
+                a.as_raw_ptr(),
+                b.as_raw_ptr(),
+                options.region.clone().into(),
+                options.thread_count as _,
+                &raw mut is_ok as _,
+            );
+
+            is_ok.assume_init()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn channel_append() -> Result<()> {
+        let mut image_buffer =
+            ImageBuffer::from_file(Utf8Path::new("assets/j0.3toD__F16_RGBA.exr"))?;
+
+        let mut luminance = image_buffer.clone();
+        luminance.channel_sum(&[0.2126, 0.7152, 0.0722, 0.0])?;
+
+        image_buffer.channel_append(&luminance)?;
+
+        assert_eq!(5, image_buffer.channel_count());
+
+        image_buffer.write(Utf8Path::new("target/channel_append.exr"))
+    }
+}
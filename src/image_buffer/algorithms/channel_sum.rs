@@ -0,0 +1,124 @@
+use crate::{algorithms::*, *};
+
+/// Optional parameters for [`ImageBuffer`]'s
+/// [`replace_by_channel_sum_with()`](ImageBuffer::replace_by_channel_sum_with)
+/// and [`channel_sum_with()`](ImageBuffer::channel_sum_with) methods.
+#[derive(Clone, Default)]
+pub struct ChannelSumOptions {
+    /// See the [Region of Interest](module@algorithms#region-of-interest)
+    /// section in the [module@algorithms] module.
+    pub region: Region,
+    /// See the [Multithreading](module@algorithms#multithreading) section
+    /// in the [module@algorithms] module.
+    pub thread_count: u16,
+}
+
+/// # Channel Sum
+///
+/// Replace the image with a single channel image whose value in each pixel is
+/// the weighted sum of the channels of the source image.
+///
+/// `weights` contains one weight per channel. If it is empty, all channels are
+/// weighted by `1.0`.
+///
+/// For example, to compute the luminance of a linear Rec709 RGB image pass
+/// `&[0.2126, 0.7152, 0.0722]`.
+impl ImageBuffer {
+    #[named]
+    pub fn replace_by_channel_sum(
+        &mut self,
+        source: &ImageBuffer,
+        weights: &[f32],
+    ) -> Result<&mut Self> {
+        let is_ok = self.channel_sum_ffi(source, weights, &ChannelSumOptions::default());
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+
+    #[named]
+    pub fn replace_by_channel_sum_with(
+        &mut self,
+        source: &ImageBuffer,
+        weights: &[f32],
+        options: &ChannelSumOptions,
+    ) -> Result<&mut Self> {
+        let is_ok = self.channel_sum_ffi(source, weights, options);
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+
+    #[named]
+    pub fn channel_sum(&mut self, weights: &[f32]) -> Result<&mut Self> {
+        let mut image_buffer = ImageBuffer::new();
+        let is_ok = image_buffer.channel_sum_ffi(self, weights, &ChannelSumOptions::default());
+        *self = image_buffer;
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+
+    #[named]
+    pub fn channel_sum_with(
+        &mut self,
+        weights: &[f32],
+        options: &ChannelSumOptions,
+    ) -> Result<&mut Self> {
+        let mut image_buffer = ImageBuffer::new();
+        let is_ok = image_buffer.channel_sum_ffi(self, weights, options);
+        *self = image_buffer;
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+}
+
+impl ImageBuffer {
+    #[inline]
+    fn channel_sum_ffi(
+        &mut self,
+        source: &ImageBuffer,
+        weights: &[f32],
+        options: &ChannelSumOptions,
+    ) -> bool {
+        let mut is_ok = MaybeUninit::<bool>::uninit();
+
+        unsafe {
+            oiio_ImageBufAlgo_channel_sum(
+                self.as_raw_ptr_mut(),
+                source.as_raw_ptr(),
+                CspanF32::new(weights).as_raw_ptr() as _,
+                options.region.clone().into(),
+                options.thread_count as _,
+                &raw mut is_ok as _,
+            );
+
+            is_ok.assume_init()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn channel_sum() -> Result<()> {
+        let mut image_buffer =
+            ImageBuffer::from_file(Utf8Path::new("assets/j0.3toD__F16_RGBA.exr"))?;
+
+        image_buffer.channel_sum(&[0.2126, 0.7152, 0.0722, 0.0])?;
+
+        assert_eq!(1, image_buffer.channel_count());
+
+        image_buffer.write(Utf8Path::new("target/channel_sum.exr"))?;
+
+        let mut image_buffer =
+            ImageBuffer::from_fill(&[0.5, 1.0, 2.0], &Bounds::new(0..4, 0..4, 0..1, Some(0..3)))?;
+        image_buffer.channel_sum(&[1.0, 2.0, 3.0])?;
+
+        assert_eq!(
+            vec![8.5],
+            image_buffer.get_pixel(0, 0, None, WrapMode::Black)
+        );
+
+        Ok(())
+    }
+}
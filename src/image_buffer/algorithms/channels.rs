@@ -1,4 +1,6 @@
 use crate::*;
+use anyhow::{anyhow, Result};
+use core::ops::Range;
 use smallvec::SmallVec;
 
 /// How to fill a resp. channel.
@@ -7,6 +9,10 @@ pub enum Channel<'a> {
     Constant(f32, Option<&'a str>),
     /// Use the channel at the given index in the source image.
     Index(u32, Option<&'a str>),
+    /// Use the channel with the given name in the source image.
+    ///
+    /// It is an error if the source image has no channel of that name.
+    Name(&'a str, Option<&'a str>),
 }
 
 /// # Channels
@@ -22,7 +28,8 @@ impl ImageBuffer {
         source: &ImageBuffer,
         order: &[Channel],
     ) -> Result<&mut Self> {
-        let is_ok = self.channels_ffi(source, order, &ChannelsOptions::default());
+        let order = source.resolve_channel_names(order, function_name!())?;
+        let is_ok = self.channels_ffi(source, &order, &ChannelsOptions::default());
 
         self.mut_self_or_error(is_ok, function_name!())
     }
@@ -34,15 +41,17 @@ impl ImageBuffer {
         order: &[Channel],
         options: &ChannelsOptions,
     ) -> Result<&mut Self> {
-        let is_ok = self.channels_ffi(source, order, options);
+        let order = source.resolve_channel_names(order, function_name!())?;
+        let is_ok = self.channels_ffi(source, &order, options);
 
         self.mut_self_or_error(is_ok, function_name!())
     }
 
     #[named]
     pub fn channels(&mut self, order: &[Channel]) -> Result<&mut Self> {
+        let order = self.resolve_channel_names(order, function_name!())?;
         let mut image_buffer = ImageBuffer::new();
-        let is_ok = image_buffer.channels_ffi(self, order, &ChannelsOptions::default());
+        let is_ok = image_buffer.channels_ffi(self, &order, &ChannelsOptions::default());
         *self = image_buffer;
 
         self.mut_self_or_error(is_ok, function_name!())
@@ -54,14 +63,96 @@ impl ImageBuffer {
         order: &[Channel],
         options: &ChannelsOptions,
     ) -> Result<&mut Self> {
+        let order = self.resolve_channel_names(order, function_name!())?;
         let mut image_buffer = ImageBuffer::new();
-        let is_ok = image_buffer.channels_ffi(self, order, options);
+        let is_ok = image_buffer.channels_ffi(self, &order, options);
         *self = image_buffer;
 
         self.mut_self_or_error(is_ok, function_name!())
     }
 }
 
+/// # Channel Selection
+///
+/// A channel `selection` is a comma-separated list of channel names, e.g.
+/// `"R,G,B"`. Each entry may contain `*` wildcards, e.g. `"diffuse.*"` selects
+/// all channels of the *diffuse* layer. Channels matched by a wildcard are
+/// selected in the order they appear in the image.
+impl ImageBuffer {
+    /// Returns the indices of the channels in `selection`, in order.
+    ///
+    /// It is an error if an entry matches no channel.
+    #[named]
+    pub fn channel_indices(&self, selection: &str) -> Result<Vec<u32>> {
+        let channel_names = self.image_spec().channel_name;
+        let mut indices = Vec::new();
+
+        for pattern in selection.split(',').map(str::trim) {
+            let count = indices.len();
+
+            indices.extend(
+                channel_names
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, channel_name)| matches_wildcard(pattern, channel_name))
+                    .map(|(index, _)| index as u32),
+            );
+
+            if count == indices.len() {
+                return Err(anyhow!(
+                    "[fn {}] No channel matches '{pattern}'",
+                    function_name!()
+                ));
+            }
+        }
+
+        Ok(indices)
+    }
+
+    /// Returns the data window restricted to the channels in `selection`.
+    ///
+    /// Use this with any operation taking a [`Region`].
+    ///
+    /// [`Bounds`] can only hold a range of channels. It is an error if the
+    /// selected channels are not consecutive and in ascending order. Use
+    /// [`select_channels()`](ImageBuffer::select_channels) to reorder the
+    /// channels first in that case.
+    #[named]
+    pub fn channel_bounds(&self, selection: &str) -> Result<Bounds> {
+        let indices = self.channel_indices(selection)?;
+        let range = channel_range(&indices).ok_or_else(|| {
+            anyhow!(
+                "[fn {}] The channels '{selection}' are not a consecutive range",
+                function_name!()
+            )
+        })?;
+
+        let mut bounds = self.data_window();
+        bounds.set_channel(range);
+
+        Ok(bounds)
+    }
+
+    /// Keep only the channels in `selection`, in the given order.
+    ///
+    /// The channel names are kept.
+    pub fn select_channels(&mut self, selection: &str) -> Result<&mut Self> {
+        let order = self
+            .channel_indices(selection)?
+            .into_iter()
+            .map(|index| Channel::Index(index, None))
+            .collect::<Vec<_>>();
+
+        self.channels_with(
+            &order,
+            &ChannelsOptions {
+                shuffle_names: true,
+                ..Default::default()
+            },
+        )
+    }
+}
+
 /// Optional parameters for [`ImageBuffer`]'s
 /// [`replace_by_convolve_with()`](ImageBuffer::replace_by_convolve_with) and
 /// [`convolve_with()`](ImageBuffer::convolve_with) methods.
@@ -82,6 +173,28 @@ pub struct ChannelsOptions {
 }
 
 impl ImageBuffer {
+    /// Replaces each [`Channel::Name`] in `order` with the [`Channel::Index`]
+    /// of that channel in `self`.
+    fn resolve_channel_names<'a>(
+        &self,
+        order: &[Channel<'a>],
+        function_name: &str,
+    ) -> Result<Vec<Channel<'a>>> {
+        order
+            .iter()
+            .map(|channel| match channel {
+                Channel::Constant(value, name) => Ok(Channel::Constant(*value, *name)),
+                Channel::Index(index, name) => Ok(Channel::Index(*index, *name)),
+                Channel::Name(source_name, name) => self
+                    .channel_index(source_name)
+                    .map(|index| Channel::Index(index, *name))
+                    .ok_or_else(|| {
+                        anyhow!("[fn {function_name}] No channel named '{source_name}'")
+                    }),
+            })
+            .collect()
+    }
+
     #[inline]
     fn channels_ffi(
         &mut self,
@@ -98,12 +211,15 @@ impl ImageBuffer {
 
         for channel in order {
             match channel {
-                Channel::Index(idx, name) => {
-                    channel_index.push(*idx as i32);
+                Channel::Index(index, name) => {
+                    channel_index.push(*index as i32);
                     channel_value.push(0.0);
                     channel_name.push(OiioString::new(name.unwrap_or("")));
                     channel_name_ptr.push(channel_name.last().unwrap().as_raw_ptr());
                 }
+                Channel::Name(..) => {
+                    unreachable!("Resolved by `resolve_channel_names()`")
+                }
                 Channel::Constant(value, name) => {
                     channel_index.push(-1);
                     channel_value.push(*value);
@@ -132,6 +248,37 @@ impl ImageBuffer {
     }
 }
 
+/// Returns the range covered by `indices` if they are consecutive and
+/// ascending.
+fn channel_range(indices: &[u32]) -> Option<Range<u32>> {
+    let start = *indices.first()?;
+
+    indices
+        .iter()
+        .zip(start..)
+        .all(|(index, expected)| *index == expected)
+        .then(|| start..start + indices.len() as u32)
+}
+
+/// Matches `name` against `pattern`, where `*` matches any number of
+/// characters.
+fn matches_wildcard(pattern: &str, name: &str) -> bool {
+    match pattern.split_once('*') {
+        None => pattern == name,
+        Some((prefix, rest)) => {
+            let Some(name) = name.strip_prefix(prefix) else {
+                return false;
+            };
+
+            // Try every split of the remaining name.
+            name.char_indices()
+                .map(|(index, _)| index)
+                .chain([name.len()])
+                .any(|index| matches_wildcard(rest, &name[index..]))
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{algorithms::*, *};
@@ -152,4 +299,45 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn channel_selection() -> Result<()> {
+        let mut image_buffer = ImageBuffer::from_fill(
+            &[0.0, 1.0, 2.0, 3.0, 4.0, 5.0],
+            &Bounds::new(0..4, 0..4, 0..1, Some(0..6)),
+        )?;
+        assert_eq!(Some(3), image_buffer.channel_index("A"));
+        assert_eq!(None, image_buffer.channel_index("diffuse.R"));
+
+        let mut shuffled = image_buffer.clone();
+        shuffled.channels(&[
+            Channel::Name("B", None),
+            Channel::Name("R", None),
+            Channel::Constant(0.5, None),
+        ])?;
+        assert_eq!(
+            vec![2.0, 0.0, 0.5],
+            shuffled.get_pixel(0, 0, None, WrapMode::Black)
+        );
+        assert!(shuffled.channels(&[Channel::Name("Q", None)]).is_err());
+
+        assert_eq!(vec![0, 1, 2], image_buffer.channel_indices("R,G,B")?);
+        assert_eq!(vec![2, 0], image_buffer.channel_indices("B, R")?);
+        assert!(image_buffer.channel_indices("R,Q").is_err());
+
+        assert_eq!(&(1..3), image_buffer.channel_bounds("G,B")?.channel());
+        assert!(image_buffer.channel_bounds("B,G").is_err());
+
+        image_buffer.select_channels("B,G")?;
+        assert_eq!(
+            vec![ustr("B"), ustr("G")],
+            image_buffer.image_spec().channel_name
+        );
+
+        assert!(super::matches_wildcard("diffuse.*", "diffuse.R"));
+        assert!(super::matches_wildcard("*.R", "diffuse.R"));
+        assert!(!super::matches_wildcard("diffuse.*", "specular.R"));
+
+        Ok(())
+    }
 }
@@ -45,6 +45,123 @@ impl ImageBuffer {
     }
 }
 
+/// # Perceptual Compare
+///
+/// Compare two images using Hector Yee's perceptual metric.
+///
+/// The `luminance` is the assumed luminance of the display in cd/m² and
+/// `field_of_view` the assumed viewing angle in degrees (OIIO uses `100.0` and
+/// `45.0` by default).
+///
+/// The resulting [`CompareResult::failure_count`] is the number of pixels that
+/// are perceptually different. Only the first three channels are compared.
+/// Free parameters are the number of pixels, the luminance and the viewing
+/// angle.
+impl ImageBuffer {
+    pub fn compare_yee(
+        &self,
+        other: &ImageBuffer,
+        luminance: f32,
+        field_of_view: f32,
+    ) -> CompareResult {
+        self.compare_yee_ffi(other, luminance, field_of_view, Options::default())
+    }
+
+    /// If `options.region` is supplied, pixels will be compared for the pixel
+    /// range that is specified.
+    pub fn compare_yee_with(
+        &self,
+        other: &ImageBuffer,
+        luminance: f32,
+        field_of_view: f32,
+        options: Options,
+    ) -> CompareResult {
+        self.compare_yee_ffi(other, luminance, field_of_view, options)
+    }
+}
+
+/// # Structural Similarity
+///
+/// Compute the [structural similarity index measure](https://en.wikipedia.org/wiki/Structural_similarity_index_measure)
+/// (SSIM) of two images.
+///
+/// The SSIM is computed per channel, using an 11×11 Gaussian window with a
+/// standard deviation of `1.5` and assuming a dynamic range of `1.0`. Each
+/// z-slice of a volume image is treated as a separate 2D image.
+///
+/// A value of `1.0` means the images are identical.
+impl ImageBuffer {
+    pub fn structural_similarity(&self, other: &ImageBuffer) -> Result<StructuralSimilarity> {
+        self.structural_similarity_impl(other, Options::default())
+    }
+
+    /// If `options.region` is supplied, the SSIM will be computed for the pixel
+    /// and channel range that is specified.
+    ///
+    /// If `options.region` is [`Region::All`] it will be computed for all
+    /// channels, on the union of the data windows of the two images.
+    ///
+    /// For either image, undefined pixels will be assumed to be black/zero.
+    pub fn structural_similarity_with(
+        &self,
+        other: &ImageBuffer,
+        options: Options,
+    ) -> Result<StructuralSimilarity> {
+        self.structural_similarity_impl(other, options)
+    }
+}
+
+/// # Difference
+///
+/// Create a visual difference image of `a` and `b`.
+///
+/// Each pixel is the absolute difference `|a - b|` multiplied by `scale`.
+/// Pixels where any channel differs by more than `failure_threshold` are
+/// highlighted, i.e. set to [`DIFFERENCE_HIGHLIGHT_COLOR`].
+impl ImageBuffer {
+    #[named]
+    pub fn from_difference(
+        a: &ImageBuffer,
+        b: &ImageBuffer,
+        scale: f32,
+        failure_threshold: f32,
+    ) -> Result<Self> {
+        let mut image_buffer = ImageBuffer::new();
+        let is_ok = image_buffer.absolute_difference_ffi(a, b, &Options::default());
+
+        image_buffer
+            .self_or_error(is_ok, function_name!())?
+            .highlight_difference(scale, failure_threshold)
+    }
+
+    /// If `options.region` is supplied, the difference image will cover the
+    /// pixel and channel range that is specified.
+    #[named]
+    pub fn from_difference_with(
+        a: &ImageBuffer,
+        b: &ImageBuffer,
+        scale: f32,
+        failure_threshold: f32,
+        options: &Options,
+    ) -> Result<Self> {
+        let mut image_buffer = ImageBuffer::new();
+        let is_ok = image_buffer.absolute_difference_ffi(a, b, options);
+
+        image_buffer
+            .self_or_error(is_ok, function_name!())?
+            .highlight_difference(scale, failure_threshold)
+    }
+}
+
+/// The color failing pixels are set to in a difference image created with
+/// [`from_difference()`](ImageBuffer::from_difference).
+///
+/// Images with less than four channels use the respective number of leading
+/// values.
+/// In images with more than four channels, the remaining channels of failing
+/// pixels are set to zero.
+pub const DIFFERENCE_HIGHLIGHT_COLOR: [f32; 4] = [1.0, 0.0, 1.0, 1.0];
+
 #[derive(Clone, Copy, Default, PartialEq, Debug)]
 #[repr(C)]
 pub struct CompareResult {
@@ -61,6 +178,16 @@ pub struct CompareResult {
     pub is_error: bool,
 }
 
+/// The result of
+/// [`structural_similarity()`](ImageBuffer::structural_similarity).
+#[derive(Clone, Default, PartialEq, Debug)]
+pub struct StructuralSimilarity {
+    /// The SSIM of each channel.
+    pub channel: Vec<f64>,
+    /// The mean SSIM over all channels.
+    pub mean: f64,
+}
+
 impl From<oiio_CompareResults_t> for CompareResult {
     fn from(compare_result: oiio_CompareResults_t) -> Self {
         unsafe { transmute(compare_result) }
@@ -94,3 +221,275 @@ impl ImageBuffer {
         }
     }
 }
+
+impl ImageBuffer {
+    #[inline]
+    fn compare_yee_ffi(
+        &self,
+        other: &ImageBuffer,
+        luminance: f32,
+        field_of_view: f32,
+        options: Options,
+    ) -> CompareResult {
+        let mut compare_result = MaybeUninit::<CompareResult>::zeroed();
+        let mut failure_count = MaybeUninit::<i32>::uninit();
+
+        unsafe {
+            oiio_ImageBufAlgo_compare_Yee(
+                self.ptr,
+                other.ptr,
+                &mut compare_result as *mut _ as _,
+                luminance,
+                field_of_view,
+                options.region.clone().into(),
+                options.thread_count as _,
+                &raw mut failure_count as _,
+            );
+
+            let mut compare_result = compare_result.assume_init();
+            // The number of perceptually different pixels.
+            compare_result.failure_count = failure_count.assume_init().max(0) as _;
+
+            compare_result
+        }
+    }
+
+    #[inline]
+    fn absolute_difference_ffi(
+        &mut self,
+        a: &ImageBuffer,
+        b: &ImageBuffer,
+        options: &Options,
+    ) -> bool {
+        let mut is_ok = MaybeUninit::<bool>::uninit();
+
+        unsafe {
+            oiio_ImageBufAlgo_absdiff(
+                self.ptr,
+                a.ptr,
+                b.ptr,
+                options.region.clone().into(),
+                options.thread_count as _,
+                &raw mut is_ok as _,
+            );
+
+            is_ok.assume_init()
+        }
+    }
+
+    fn highlight_difference(mut self, scale: f32, failure_threshold: f32) -> Result<Self> {
+        let channel_count = self.channel_count() as usize;
+        let mut pixels: Vec<f32> = self.pixels(&Region::All)?;
+
+        pixels.chunks_exact_mut(channel_count).for_each(|pixel| {
+            if pixel.iter().any(|value| failure_threshold < *value) {
+                // Channels beyond the highlight color are cleared.
+                pixel
+                    .iter_mut()
+                    .zip(
+                        DIFFERENCE_HIGHLIGHT_COLOR
+                            .into_iter()
+                            .chain(core::iter::repeat(0.0)),
+                    )
+                    .for_each(|(value, highlight)| *value = highlight);
+            } else {
+                pixel.iter_mut().for_each(|value| *value *= scale);
+            }
+        });
+
+        self.set_pixels(&pixels, &Region::All)?;
+
+        Ok(self)
+    }
+
+    fn structural_similarity_impl(
+        &self,
+        other: &ImageBuffer,
+        options: Options,
+    ) -> Result<StructuralSimilarity> {
+        let bounds = match options.region {
+            Region::All => Bounds::from_union(&self.data_window(), &other.data_window()),
+            Region::Bounds(bounds) => bounds,
+        };
+
+        if bounds.is_empty() {
+            return Err(anyhow!(
+                "ImageBuffer::structural_similarity(): region is empty"
+            ));
+        }
+
+        let region = Region::Bounds(bounds.clone());
+        let a: Vec<f32> = self.pixels(&region)?;
+        let b: Vec<f32> = other.pixels(&region)?;
+
+        let size = bounds.pixel_count() * bounds.channel_count() as usize;
+        if a.len() != size || b.len() != size {
+            return Err(anyhow!(
+                "ImageBuffer::structural_similarity(): image is uninitialized"
+            ));
+        }
+
+        let width = bounds.width() as usize;
+        let height = bounds.height() as usize;
+        let channel_count = bounds.channel_count() as usize;
+        let slice_size = width * height * channel_count;
+
+        let kernel = ssim::gaussian_kernel();
+
+        let channel = (0..channel_count)
+            .map(|channel| {
+                let (sum, count) = a
+                    .chunks_exact(slice_size)
+                    .zip(b.chunks_exact(slice_size))
+                    .map(|(a, b)| {
+                        let a = a
+                            .iter()
+                            .skip(channel)
+                            .step_by(channel_count)
+                            .map(|v| *v as f64)
+                            .collect::<Vec<_>>();
+                        let b = b
+                            .iter()
+                            .skip(channel)
+                            .step_by(channel_count)
+                            .map(|v| *v as f64)
+                            .collect::<Vec<_>>();
+
+                        ssim::plane(&a, &b, width, height, &kernel)
+                    })
+                    .fold((0.0, 0usize), |(sum, count), (s, c)| (sum + s, count + c));
+
+                sum / count as f64
+            })
+            .collect::<Vec<_>>();
+
+        let mean = channel.iter().sum::<f64>() / channel.len() as f64;
+
+        Ok(StructuralSimilarity { channel, mean })
+    }
+}
+
+mod ssim {
+    // Stabilization constants for a dynamic range of 1.0.
+    const C1: f64 = 0.01 * 0.01;
+    const C2: f64 = 0.03 * 0.03;
+
+    const RADIUS: isize = 5;
+    const SIGMA: f64 = 1.5;
+
+    pub(super) fn gaussian_kernel() -> Vec<f64> {
+        let kernel = (-RADIUS..=RADIUS)
+            .map(|i| (-((i * i) as f64) / (2.0 * SIGMA * SIGMA)).exp())
+            .collect::<Vec<_>>();
+        let sum = kernel.iter().sum::<f64>();
+
+        kernel.into_iter().map(|w| w / sum).collect()
+    }
+
+    // Separable blur; taps outside the plane are dropped and the remaining
+    // weights renormalized.
+    fn blur(plane: &[f64], width: usize, height: usize, kernel: &[f64]) -> Vec<f64> {
+        let convolve = |src: &[f64], stride: usize, len: usize, index: usize, at: usize| {
+            let (sum, weight) =
+                kernel
+                    .iter()
+                    .enumerate()
+                    .fold((0.0, 0.0), |(sum, weight), (k, w)| {
+                        let i = at as isize + k as isize - RADIUS;
+                        if 0 <= i && (i as usize) < len {
+                            (sum + w * src[index + i as usize * stride], weight + w)
+                        } else {
+                            (sum, weight)
+                        }
+                    });
+            sum / weight
+        };
+
+        let horizontal = (0..width * height)
+            .map(|i| convolve(plane, 1, width, i - i % width, i % width))
+            .collect::<Vec<_>>();
+
+        (0..width * height)
+            .map(|i| convolve(&horizontal, width, height, i % width, i / width))
+            .collect()
+    }
+
+    // Returns the sum of the SSIM map and the number of pixels.
+    pub(super) fn plane(
+        a: &[f64],
+        b: &[f64],
+        width: usize,
+        height: usize,
+        kernel: &[f64],
+    ) -> (f64, usize) {
+        let product =
+            |x: &[f64], y: &[f64]| x.iter().zip(y).map(|(x, y)| x * y).collect::<Vec<_>>();
+
+        let mu_a = blur(a, width, height, kernel);
+        let mu_b = blur(b, width, height, kernel);
+        let a_a = blur(&product(a, a), width, height, kernel);
+        let b_b = blur(&product(b, b), width, height, kernel);
+        let a_b = blur(&product(a, b), width, height, kernel);
+
+        let sum = (0..width * height)
+            .map(|i| {
+                let sigma_a = a_a[i] - mu_a[i] * mu_a[i];
+                let sigma_b = b_b[i] - mu_b[i] * mu_b[i];
+                let sigma_ab = a_b[i] - mu_a[i] * mu_b[i];
+
+                ((2.0 * mu_a[i] * mu_b[i] + C1) * (2.0 * sigma_ab + C2))
+                    / ((mu_a[i] * mu_a[i] + mu_b[i] * mu_b[i] + C1) * (sigma_a + sigma_b + C2))
+            })
+            .sum();
+
+        (sum, width * height)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{algorithms::*, *};
+
+    #[test]
+    fn compare() -> Result<()> {
+        let image_buffer = ImageBuffer::from_file(Utf8Path::new("assets/j0.3toD__F16_RGBA.exr"))?;
+
+        let mut noisy = image_buffer.clone();
+        noisy.noise(NoiseType::default())?;
+
+        let ssim = image_buffer.structural_similarity(&image_buffer)?;
+        assert!(ssim.channel.iter().all(|s| (1.0 - s).abs() < 1e-6));
+
+        let ssim = image_buffer.structural_similarity(&noisy)?;
+        assert!(ssim.mean < 1.0);
+
+        assert_eq!(
+            0,
+            image_buffer
+                .compare_yee(&image_buffer, 100.0, 45.0)
+                .failure_count
+        );
+
+        let bounds = Bounds::new(0..32, 0..32, 0..1, Some(0..3));
+        let dark = ImageBuffer::from_fill(&[0.1, 0.1, 0.1], &bounds)?;
+        let bright = ImageBuffer::from_fill(&[0.9, 0.9, 0.9], &bounds)?;
+        assert!(0 < dark.compare_yee(&bright, 100.0, 45.0).failure_count);
+
+        let difference = ImageBuffer::from_difference(&image_buffer, &noisy, 4.0, 0.2)?;
+        difference.write(Utf8Path::new("target/difference.exr"))?;
+
+        // Extra channels of failing pixels are cleared, not left unscaled.
+        let a = ImageBuffer::from_fill(&[0.0; 5], &Bounds::new(0..2, 0..2, 0..1, Some(0..5)))?;
+        let b = ImageBuffer::from_fill(
+            &[1.0, 0.0, 0.0, 0.0, 0.5],
+            &Bounds::new(0..2, 0..2, 0..1, Some(0..5)),
+        )?;
+        let difference = ImageBuffer::from_difference(&a, &b, 4.0, 0.2)?;
+        assert_eq!(
+            vec![1.0, 0.0, 1.0, 1.0, 0.0],
+            difference.get_pixel(0, 0, None, WrapMode::Black)
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,313 @@
+use crate::{algorithms::*, *};
+
+/// The operator used by [`ImageBuffer`]'s
+/// [`composite()`](ImageBuffer::composite) method.
+///
+/// All operators expect *premultiplied* (associated) alpha. `Fg`/`Bg` denote
+/// the (premultiplied) color and `αf`/`αb` the alpha of the foreground resp.
+/// background.
+///
+/// The first six operators are the
+/// [Porter-Duff](https://en.wikipedia.org/wiki/Alpha_compositing) operators.
+/// The remaining ones are the separable blend modes as specified by the
+/// [W3C Compositing and Blending](https://www.w3.org/TR/compositing-1/#blending)
+/// recommendation, composited *source-over*. I.e. where the foreground is
+/// transparent they show the background and vice versa.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Operator {
+    /// `Fg + Bg × (1 - αf)`.
+    #[default]
+    Over,
+    /// `Fg × αb`. The foreground where the background is opaque.
+    In,
+    /// `Fg × (1 - αb)`. The foreground where the background is transparent.
+    Out,
+    /// `Fg × αb + Bg × (1 - αf)`. The foreground *in* the background, over the
+    /// background.
+    Atop,
+    /// `Fg × (1 - αb) + Bg × (1 - αf)`. Foreground and background where they
+    /// do not overlap.
+    Xor,
+    /// `Fg + Bg`. Also known as *add*. Values are not clamped.
+    Plus,
+    /// `fg × bg`.
+    Multiply,
+    /// `fg + bg - fg × bg`.
+    Screen,
+    /// [`Multiply`](Operator::Multiply) or [`Screen`](Operator::Screen),
+    /// depending on the background color. Dark background areas get darker,
+    /// light ones lighter.
+    Overlay,
+    /// `|fg - bg|`.
+    Difference,
+}
+
+impl Operator {
+    /// Returns the premultiplied result color for one channel.
+    #[inline]
+    fn color(self, fg: f32, fg_alpha: f32, bg: f32, bg_alpha: f32) -> f32 {
+        match self {
+            Self::Over => fg + bg * (1.0 - fg_alpha),
+            Self::In => fg * bg_alpha,
+            Self::Out => fg * (1.0 - bg_alpha),
+            Self::Atop => fg * bg_alpha + bg * (1.0 - fg_alpha),
+            Self::Xor => fg * (1.0 - bg_alpha) + bg * (1.0 - fg_alpha),
+            Self::Plus => fg + bg,
+            Self::Multiply | Self::Screen | Self::Overlay | Self::Difference => {
+                let unpremultiply =
+                    |color: f32, alpha: f32| if 0.0 < alpha { color / alpha } else { 0.0 };
+
+                let fg_straight = unpremultiply(fg, fg_alpha);
+                let bg_straight = unpremultiply(bg, bg_alpha);
+
+                let blended = match self {
+                    Self::Multiply => fg_straight * bg_straight,
+                    Self::Screen => fg_straight + bg_straight - fg_straight * bg_straight,
+                    Self::Overlay => {
+                        if bg_straight <= 0.5 {
+                            2.0 * fg_straight * bg_straight
+                        } else {
+                            1.0 - 2.0 * (1.0 - fg_straight) * (1.0 - bg_straight)
+                        }
+                    }
+                    _ => (fg_straight - bg_straight).abs(),
+                };
+
+                fg * (1.0 - bg_alpha) + bg * (1.0 - fg_alpha) + fg_alpha * bg_alpha * blended
+            }
+        }
+    }
+
+    /// Returns the result alpha.
+    #[inline]
+    fn alpha(self, fg_alpha: f32, bg_alpha: f32) -> f32 {
+        match self {
+            Self::In => fg_alpha * bg_alpha,
+            Self::Out => fg_alpha * (1.0 - bg_alpha),
+            Self::Atop => bg_alpha,
+            Self::Xor => fg_alpha * (1.0 - bg_alpha) + bg_alpha * (1.0 - fg_alpha),
+            Self::Plus => fg_alpha + bg_alpha,
+            _ => fg_alpha + bg_alpha * (1.0 - fg_alpha),
+        }
+    }
+}
+
+/// # Composite
+///
+/// Composite `foreground` with `background` using the given [`Operator`].
+///
+/// The alpha channel of each image is read from its [`ImageSpec`]'s
+/// [`alpha_channel_index`](ImageSpec::alpha_channel_index). An image without
+/// an alpha channel is treated as fully opaque. Both images must have the same
+/// number of channels and, if both have an alpha channel, it must be at the
+/// same index. All other channels are treated as color.
+///
+/// [`Operator::Over`] is computed by [`over()`](ImageBuffer::over). For all
+/// other operators the result is a [`F32`](BaseType::F32) image with the
+/// channel names of the background that covers the union of both data windows
+/// (or the pixels of `options.region`, if given).
+///
+/// Only the channels in the channel range of `options.region` are composited.
+/// All other channels keep the values of the background. It is an error if
+/// that range lies outside of the channels of the images.
+impl ImageBuffer {
+    pub fn replace_by_composite(
+        &mut self,
+        foreground: &ImageBuffer,
+        background: &ImageBuffer,
+        operator: Operator,
+    ) -> Result<&mut Self> {
+        self.composite_impl(foreground, background, operator, &Options::default())?;
+
+        Ok(self)
+    }
+
+    pub fn replace_by_composite_with(
+        &mut self,
+        foreground: &ImageBuffer,
+        background: &ImageBuffer,
+        operator: Operator,
+        options: &Options,
+    ) -> Result<&mut Self> {
+        self.composite_impl(foreground, background, operator, options)?;
+
+        Ok(self)
+    }
+
+    /// Composite `foreground` with this image as the background.
+    pub fn composite(&mut self, foreground: &ImageBuffer, operator: Operator) -> Result<&mut Self> {
+        let mut image_buffer = ImageBuffer::new();
+        image_buffer.composite_impl(foreground, self, operator, &Options::default())?;
+        *self = image_buffer;
+
+        Ok(self)
+    }
+
+    /// Composite `foreground` with this image as the background.
+    pub fn composite_with(
+        &mut self,
+        foreground: &ImageBuffer,
+        operator: Operator,
+        options: &Options,
+    ) -> Result<&mut Self> {
+        let mut image_buffer = ImageBuffer::new();
+        image_buffer.composite_impl(foreground, self, operator, options)?;
+        *self = image_buffer;
+
+        Ok(self)
+    }
+}
+
+impl ImageBuffer {
+    #[named]
+    fn composite_impl(
+        &mut self,
+        foreground: &ImageBuffer,
+        background: &ImageBuffer,
+        operator: Operator,
+        options: &Options,
+    ) -> Result<()> {
+        if Operator::Over == operator {
+            let is_ok = self.over_ffi(foreground, background, options);
+
+            return self.mut_self_or_error(is_ok, function_name!()).map(|_| ());
+        }
+
+        let channel_count = background.channel_count();
+        if foreground.channel_count() != channel_count {
+            return Err(anyhow!(
+                "[fn {}] Foreground has {} channels but background has {}",
+                function_name!(),
+                foreground.channel_count(),
+                channel_count
+            ));
+        }
+
+        let foreground_spec = foreground.image_spec();
+        let background_spec = background.image_spec();
+
+        let alpha_channel_index = match (
+            foreground_spec.alpha_channel_index,
+            background_spec.alpha_channel_index,
+        ) {
+            (Some(fg), Some(bg)) if fg != bg => {
+                return Err(anyhow!(
+                    "[fn {}] Foreground alpha channel index {} does not match background alpha channel index {}",
+                    function_name!(),
+                    fg,
+                    bg
+                ));
+            }
+            (fg, bg) => bg.or(fg),
+        };
+
+        let mut bounds = match &options.region {
+            Region::All => Bounds::from_union(&foreground.data_window(), &background.data_window()),
+            Region::Bounds(bounds) => bounds.clone(),
+        };
+
+        // Only these channels are composited but all are read as the alpha
+        // may lie outside.
+        let channel_range =
+            bounds.channel().start.min(channel_count)..bounds.channel().end.min(channel_count);
+        if channel_range.is_empty() {
+            return Err(anyhow!(
+                "[fn {}] Channel range {:?} is outside of the {} image channels",
+                function_name!(),
+                bounds.channel(),
+                channel_count
+            ));
+        }
+        bounds.set_channel(0..channel_count);
+
+        let region = Region::Bounds(bounds.clone());
+        let foreground_pixels: Vec<f32> = foreground.pixels(&region)?;
+        let mut pixels: Vec<f32> = background.pixels(&region)?;
+
+        let alpha = |pixel: &[f32], index: Option<u32>| index.map_or(1.0, |i| pixel[i as usize]);
+
+        pixels
+            .chunks_exact_mut(channel_count as _)
+            .zip(foreground_pixels.chunks_exact(channel_count as _))
+            .for_each(|(bg, fg)| {
+                let fg_alpha = alpha(fg, foreground_spec.alpha_channel_index);
+                let bg_alpha = alpha(bg, background_spec.alpha_channel_index);
+
+                bg.iter_mut()
+                    .zip(fg)
+                    .enumerate()
+                    .filter(|(channel, _)| channel_range.contains(&(*channel as u32)))
+                    .for_each(|(channel, (bg, fg))| {
+                        *bg = if Some(channel as u32) == alpha_channel_index {
+                            operator.alpha(fg_alpha, bg_alpha)
+                        } else {
+                            operator.color(*fg, fg_alpha, *bg, bg_alpha)
+                        };
+                    });
+            });
+
+        *self = ImageBuffer::new_with(
+            &ImageSpec {
+                x: bounds.x_start(),
+                y: bounds.y_start(),
+                z: bounds.z_start(),
+                width: bounds.width(),
+                height: bounds.height(),
+                depth: bounds.depth(),
+                channel_format: ChannelFormat::Uniform(BaseType::F32, channel_count as _),
+                alpha_channel_index,
+                ..background_spec
+            },
+            InitializePixels::No,
+        );
+
+        self.set_pixels(&pixels, &region)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{algorithms::*, *};
+
+    #[test]
+    fn composite() -> Result<()> {
+        let bounds = Bounds::new(0..64, 0..64, 0..1, Some(0..4));
+
+        let mut background = ImageBuffer::from_fill(&[0.5, 0.5, 0.5, 1.0], &bounds)?;
+        let foreground = ImageBuffer::from_fill(&[0.25, 0.0, 0.0, 0.5], &bounds)?;
+
+        let mut multiplied = background.clone();
+        multiplied.composite(&foreground, Operator::Multiply)?;
+
+        // Red: 0.25 × 0.0 + 0.5 × 0.5 + 0.5 × 1.0 × (0.5 × 0.5)
+        assert_eq!(
+            Some(vec![0.375, 0.25, 0.25, 1.0]),
+            multiplied.is_constant_color(1.0e-6)
+        );
+
+        // Only the red channel.
+        let mut red_only = background.clone();
+        red_only.composite_with(
+            &foreground,
+            Operator::Multiply,
+            &Options {
+                region: Bounds::new(0..64, 0..64, 0..1, Some(0..1)).into(),
+                ..Default::default()
+            },
+        )?;
+
+        assert_eq!(
+            Some(vec![0.375, 0.5, 0.5, 1.0]),
+            red_only.is_constant_color(1.0e-6)
+        );
+
+        background.composite(&foreground, Operator::In)?;
+
+        assert_eq!(
+            Some(vec![0.25, 0.0, 0.0, 0.5]),
+            background.is_constant_color(1.0e-6)
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,172 @@
+use crate::{algorithms::*, *};
+
+/// Optional parameters for [`ImageBuffer`]'s
+/// [`replace_by_contrast_remap_with()`](ImageBuffer::replace_by_contrast_remap_with)
+/// and [`contrast_remap_with()`](ImageBuffer::contrast_remap_with) methods.
+///
+/// All values can either be given per channel or as a single value that is
+/// used for all channels.
+#[derive(Clone)]
+pub struct ContrastRemapOptions {
+    /// The output value that input values at `black` are mapped to.
+    pub min: Vec<f32>,
+    /// The output value that input values at `white` are mapped to.
+    pub max: Vec<f32>,
+    /// The contrast of the sigmoidal remapping. A value of `1.0` (the default)
+    /// means no sigmoidal remapping is applied.
+    ///
+    /// Values > `1.0` increase contrast, values < `1.0` decrease it.
+    pub sigmoid_contrast: Vec<f32>,
+    /// The input value that is the midpoint of the sigmoidal remapping.
+    pub sigmoid_threshold: Vec<f32>,
+    /// See the [Region of Interest](module@algorithms#region-of-interest)
+    /// section in the [module@algorithms] module. .
+    pub region: Region,
+    /// See the [Multithreading](module@algorithms#multithreading) section
+    /// in the [module@algorithms] module.
+    pub thread_count: u16,
+}
+
+impl Default for ContrastRemapOptions {
+    fn default() -> Self {
+        Self {
+            min: vec![0.0],
+            max: vec![1.0],
+            sigmoid_contrast: vec![1.0],
+            sigmoid_threshold: vec![0.5],
+            region: Region::default(),
+            thread_count: 0,
+        }
+    }
+}
+
+/// # Contrast Remap
+///
+/// Remap contrast by transforming values in the range `black`…`white` to the
+/// new range [`min`](ContrastRemapOptions::min)…[`max`](ContrastRemapOptions::max),
+/// either linearly or with optional application of a smooth sigmoidal remapping
+/// (if [`sigmoid_contrast`](ContrastRemapOptions::sigmoid_contrast) != `1.0`).
+///
+/// Input values below `black` or above `white` are extrapolated linearly. If
+/// `black` == `white` the result is a simple binary threshold where values
+/// below `black` map to `min` and values at or above map to `max`.
+///
+/// `black` and `white` can either be given per channel or as a single value
+/// that is used for all channels.
+///
+/// ## For C++ Developers
+///
+/// The C++ names of the sigmoid options are `scontrast` and `sthresh`.
+impl ImageBuffer {
+    #[named]
+    pub fn replace_by_contrast_remap(
+        &mut self,
+        source: &ImageBuffer,
+        black: &[f32],
+        white: &[f32],
+    ) -> Result<&mut Self> {
+        let is_ok = self.contrast_remap_ffi(source, black, white, &ContrastRemapOptions::default());
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+
+    #[named]
+    pub fn replace_by_contrast_remap_with(
+        &mut self,
+        source: &ImageBuffer,
+        black: &[f32],
+        white: &[f32],
+        options: &ContrastRemapOptions,
+    ) -> Result<&mut Self> {
+        let is_ok = self.contrast_remap_ffi(source, black, white, options);
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+
+    #[named]
+    pub fn contrast_remap(&mut self, black: &[f32], white: &[f32]) -> Result<&mut Self> {
+        let mut image_buffer = ImageBuffer::new();
+        let is_ok =
+            image_buffer.contrast_remap_ffi(self, black, white, &ContrastRemapOptions::default());
+        *self = image_buffer;
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+
+    #[named]
+    pub fn contrast_remap_with(
+        &mut self,
+        black: &[f32],
+        white: &[f32],
+        options: &ContrastRemapOptions,
+    ) -> Result<&mut Self> {
+        let mut image_buffer = ImageBuffer::new();
+        let is_ok = image_buffer.contrast_remap_ffi(self, black, white, options);
+        *self = image_buffer;
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+}
+
+impl ImageBuffer {
+    #[inline]
+    fn contrast_remap_ffi(
+        &mut self,
+        source: &ImageBuffer,
+        black: &[f32],
+        white: &[f32],
+        options: &ContrastRemapOptions,
+    ) -> bool {
+        let mut is_ok = MaybeUninit::<bool>::uninit();
+
+        unsafe {
+            oiio_ImageBufAlgo_contrast_remap(
+                self.as_raw_ptr_mut(),
+                source.as_raw_ptr(),
+                CspanF32::new(black).as_raw_ptr() as _,
+                CspanF32::new(white).as_raw_ptr() as _,
+                CspanF32::new(&options.min).as_raw_ptr() as _,
+                CspanF32::new(&options.max).as_raw_ptr() as _,
+                CspanF32::new(&options.sigmoid_contrast).as_raw_ptr() as _,
+                CspanF32::new(&options.sigmoid_threshold).as_raw_ptr() as _,
+                options.region.clone().into(),
+                options.thread_count as _,
+                &raw mut is_ok as _,
+            );
+
+            is_ok.assume_init()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{algorithms::*, *};
+
+    #[test]
+    fn contrast_remap() -> Result<()> {
+        let mut image_buffer =
+            ImageBuffer::from_file(Utf8Path::new("assets/j0.3toD__F16_RGBA.exr"))?;
+
+        image_buffer.contrast_remap_with(
+            &[0.1],
+            &[0.75],
+            &ContrastRemapOptions {
+                sigmoid_contrast: vec![5.0],
+                ..Default::default()
+            },
+        )?;
+
+        image_buffer.write(Utf8Path::new("target/contrast_remap.exr"))?;
+
+        // Linear remapping of `0.25..0.75` to `0..1`.
+        let mut image_buffer =
+            ImageBuffer::from_fill(&[0.375], &Bounds::new(0..4, 0..4, 0..1, Some(0..1)))?;
+        image_buffer.contrast_remap(&[0.25], &[0.75])?;
+
+        let value = image_buffer.get_pixel(0, 0, None, WrapMode::Black)[0];
+        assert!((0.25 - value).abs() < 1.0e-6);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,251 @@
+use crate::{algorithms::*, *};
+
+/// # Fast Fourier Transform
+///
+/// Compute the discrete Fourier transform of a single channel of the source
+/// image.
+///
+/// The result is a two-channel [`F32`](BaseType::F32) image holding the real
+/// and imaginary parts (in that order) of each frequency, with the *DC*
+/// component at the origin. The channel that is transformed is the first
+/// channel of `options.region`.
+///
+/// # Examples
+///
+/// Scale frequencies by a real-valued `weight` image (e.g. a low-pass mask),
+/// stored in both channels:
+///
+/// ```ignore
+/// image_buffer.fft()?.mul(&weight)?.ifft()?;
+/// ```
+impl ImageBuffer {
+    #[named]
+    pub fn replace_by_fft(&mut self, source: &ImageBuffer) -> Result<&mut Self> {
+        let is_ok = self.source_region_ffi(oiio_ImageBufAlgo_fft, source, &Options::default());
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+
+    #[named]
+    pub fn replace_by_fft_with(
+        &mut self,
+        source: &ImageBuffer,
+        options: &Options,
+    ) -> Result<&mut Self> {
+        let is_ok = self.source_region_ffi(oiio_ImageBufAlgo_fft, source, options);
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+
+    #[named]
+    pub fn fft(&mut self) -> Result<&mut Self> {
+        let mut image_buffer = ImageBuffer::new();
+        let is_ok =
+            image_buffer.source_region_ffi(oiio_ImageBufAlgo_fft, self, &Options::default());
+        *self = image_buffer;
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+
+    #[named]
+    pub fn fft_with(&mut self, options: &Options) -> Result<&mut Self> {
+        let mut image_buffer = ImageBuffer::new();
+        let is_ok = image_buffer.source_region_ffi(oiio_ImageBufAlgo_fft, self, options);
+        *self = image_buffer;
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+}
+
+/// # Inverse Fast Fourier Transform
+///
+/// Compute the inverse discrete Fourier transform of a two-channel
+/// real/imaginary image as produced by [`fft()`](ImageBuffer::fft).
+///
+/// The result is a single-channel [`F32`](BaseType::F32) image with the real
+/// part of the inverse transform. The imaginary part is discarded.
+impl ImageBuffer {
+    #[named]
+    pub fn replace_by_ifft(&mut self, source: &ImageBuffer) -> Result<&mut Self> {
+        let is_ok = self.source_region_ffi(oiio_ImageBufAlgo_ifft, source, &Options::default());
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+
+    #[named]
+    pub fn replace_by_ifft_with(
+        &mut self,
+        source: &ImageBuffer,
+        options: &Options,
+    ) -> Result<&mut Self> {
+        let is_ok = self.source_region_ffi(oiio_ImageBufAlgo_ifft, source, options);
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+
+    #[named]
+    pub fn ifft(&mut self) -> Result<&mut Self> {
+        let mut image_buffer = ImageBuffer::new();
+        let is_ok =
+            image_buffer.source_region_ffi(oiio_ImageBufAlgo_ifft, self, &Options::default());
+        *self = image_buffer;
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+
+    #[named]
+    pub fn ifft_with(&mut self, options: &Options) -> Result<&mut Self> {
+        let mut image_buffer = ImageBuffer::new();
+        let is_ok = image_buffer.source_region_ffi(oiio_ImageBufAlgo_ifft, self, options);
+        *self = image_buffer;
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+}
+
+/// # Complex to Polar
+///
+/// Convert a two-channel real/imaginary image (as produced by
+/// [`fft()`](ImageBuffer::fft)) to a two-channel amplitude/phase image.
+///
+/// The phase is in radians, in the range `[0, 2π)`.
+impl ImageBuffer {
+    #[named]
+    pub fn replace_by_complex_to_polar(&mut self, source: &ImageBuffer) -> Result<&mut Self> {
+        let is_ok = self.source_region_ffi(
+            oiio_ImageBufAlgo_complex_to_polar,
+            source,
+            &Options::default(),
+        );
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+
+    #[named]
+    pub fn replace_by_complex_to_polar_with(
+        &mut self,
+        source: &ImageBuffer,
+        options: &Options,
+    ) -> Result<&mut Self> {
+        let is_ok = self.source_region_ffi(oiio_ImageBufAlgo_complex_to_polar, source, options);
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+
+    #[named]
+    pub fn complex_to_polar(&mut self) -> Result<&mut Self> {
+        let mut image_buffer = ImageBuffer::new();
+        let is_ok = image_buffer.source_region_ffi(
+            oiio_ImageBufAlgo_complex_to_polar,
+            self,
+            &Options::default(),
+        );
+        *self = image_buffer;
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+
+    #[named]
+    pub fn complex_to_polar_with(&mut self, options: &Options) -> Result<&mut Self> {
+        let mut image_buffer = ImageBuffer::new();
+        let is_ok =
+            image_buffer.source_region_ffi(oiio_ImageBufAlgo_complex_to_polar, self, options);
+        *self = image_buffer;
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+}
+
+/// # Polar to Complex
+///
+/// Convert a two-channel amplitude/phase image (as produced by
+/// [`complex_to_polar()`](ImageBuffer::complex_to_polar)) back to a
+/// two-channel real/imaginary image.
+impl ImageBuffer {
+    #[named]
+    pub fn replace_by_polar_to_complex(&mut self, source: &ImageBuffer) -> Result<&mut Self> {
+        let is_ok = self.source_region_ffi(
+            oiio_ImageBufAlgo_polar_to_complex,
+            source,
+            &Options::default(),
+        );
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+
+    #[named]
+    pub fn replace_by_polar_to_complex_with(
+        &mut self,
+        source: &ImageBuffer,
+        options: &Options,
+    ) -> Result<&mut Self> {
+        let is_ok = self.source_region_ffi(oiio_ImageBufAlgo_polar_to_complex, source, options);
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+
+    #[named]
+    pub fn polar_to_complex(&mut self) -> Result<&mut Self> {
+        let mut image_buffer = ImageBuffer::new();
+        let is_ok = image_buffer.source_region_ffi(
+            oiio_ImageBufAlgo_polar_to_complex,
+            self,
+            &Options::default(),
+        );
+        *self = image_buffer;
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+
+    #[named]
+    pub fn polar_to_complex_with(&mut self, options: &Options) -> Result<&mut Self> {
+        let mut image_buffer = ImageBuffer::new();
+        let is_ok =
+            image_buffer.source_region_ffi(oiio_ImageBufAlgo_polar_to_complex, self, options);
+        *self = image_buffer;
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{algorithms::*, *};
+
+    #[test]
+    fn fft() -> Result<()> {
+        let mut image_buffer =
+            ImageBuffer::from_file(Utf8Path::new("assets/j0.3toD__F16_RGBA.exr"))?;
+        // Only the first channel is transformed.
+        image_buffer.channels(&[Channel::Index(0, None)])?;
+
+        let mut transformed = image_buffer.clone();
+        transformed.fft()?;
+        assert_eq!(2, transformed.channel_count());
+
+        transformed.complex_to_polar()?.polar_to_complex()?.ifft()?;
+        assert_eq!(1, transformed.channel_count());
+
+        assert_eq!(
+            0,
+            image_buffer
+                .compare(&transformed, 1.0e-3, 1.0e-3)
+                .failure_count
+        );
+
+        // Halving every frequency halves the image.
+        let mut filtered = image_buffer.clone();
+        filtered.fft()?;
+        let weight = ImageBuffer::from_fill(&[0.5, 0.5], &filtered.data_window())?;
+        filtered.mul(&weight)?.ifft()?;
+
+        let mut bounds = image_buffer.data_window();
+        bounds.set_channel(0..1);
+        let mut halved = image_buffer.clone();
+        halved.mul(&ImageBuffer::from_fill(&[0.5], &bounds)?)?;
+
+        assert_eq!(0, halved.compare(&filtered, 1.0e-3, 1.0e-3).failure_count);
+
+        Ok(())
+    }
+}
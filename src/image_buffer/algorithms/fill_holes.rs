@@ -0,0 +1,273 @@
+use crate::{algorithms::*, *};
+
+/// # Fill Holes (Push-Pull)
+///
+/// Fill the holes of an image with partial or zero alpha using the *push-pull*
+/// technique.
+///
+/// The alpha channel is the one given by the source's [`ImageSpec`]'s
+/// [`alpha_channel_index`](ImageSpec::alpha_channel_index). It is an error if
+/// the source has no alpha channel.
+///
+/// The image is successively halved in resolution (*pull*) and the lower
+/// resolution images are then composited *under* the higher resolution ones
+/// (*push*). The result is fully opaque. Colors bleed smoothly from the
+/// defined areas into the holes.
+///
+/// This is useful to avoid dark or otherwise wrong colored fringes when
+/// MIP-mapping or filtering texture atlases and bakes that have transparent
+/// gaps.
+///
+/// ## For C++ Developers
+///
+/// The C++ name of this function is `fillholes_pushpull()`.
+impl ImageBuffer {
+    #[named]
+    pub fn replace_by_fill_holes_push_pull(&mut self, source: &ImageBuffer) -> Result<&mut Self> {
+        let is_ok = self.fill_holes_push_pull_ffi(source, &Options::default());
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+
+    #[named]
+    pub fn replace_by_fill_holes_push_pull_with(
+        &mut self,
+        source: &ImageBuffer,
+        options: &Options,
+    ) -> Result<&mut Self> {
+        let is_ok = self.fill_holes_push_pull_ffi(source, options);
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+
+    #[named]
+    pub fn fill_holes_push_pull(&mut self) -> Result<&mut Self> {
+        let mut image_buffer = ImageBuffer::new();
+        let is_ok = image_buffer.fill_holes_push_pull_ffi(self, &Options::default());
+        *self = image_buffer;
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+
+    #[named]
+    pub fn fill_holes_push_pull_with(&mut self, options: &Options) -> Result<&mut Self> {
+        let mut image_buffer = ImageBuffer::new();
+        let is_ok = image_buffer.fill_holes_push_pull_ffi(self, options);
+        *self = image_buffer;
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+}
+
+/// # Edge Padding
+///
+/// Bleed the colors of the opaque areas of an image `pixel_count` pixels into
+/// the adjacent fully transparent areas.
+///
+/// This is the classic *edge padding* (aka *dilation* or *gutter*) used when
+/// baking textures. Each pass visits every transparent pixel that touches (in
+/// any of the eight directions) a pixel which is either part of the image or
+/// was filled by a previous pass. It is set to the average (unpremultiplied)
+/// color of these neighbors and made fully opaque.
+///
+/// Pixels farther than `pixel_count` from the image stay untouched. Use
+/// [`fill_holes_push_pull()`](ImageBuffer::fill_holes_push_pull) to fill all
+/// holes.
+///
+/// The alpha channel is the one given by the source's [`ImageSpec`]'s
+/// [`alpha_channel_index`](ImageSpec::alpha_channel_index). It is an error if
+/// the source has no alpha channel.
+impl ImageBuffer {
+    pub fn replace_by_edge_padding(
+        &mut self,
+        source: &ImageBuffer,
+        pixel_count: u32,
+    ) -> Result<&mut Self> {
+        *self = source.clone();
+        self.edge_padding_impl(pixel_count, &Options::default())?;
+
+        Ok(self)
+    }
+
+    pub fn replace_by_edge_padding_with(
+        &mut self,
+        source: &ImageBuffer,
+        pixel_count: u32,
+        options: &Options,
+    ) -> Result<&mut Self> {
+        *self = source.clone();
+        self.edge_padding_impl(pixel_count, options)?;
+
+        Ok(self)
+    }
+
+    pub fn edge_padding(&mut self, pixel_count: u32) -> Result<&mut Self> {
+        self.edge_padding_impl(pixel_count, &Options::default())?;
+
+        Ok(self)
+    }
+
+    pub fn edge_padding_with(&mut self, pixel_count: u32, options: &Options) -> Result<&mut Self> {
+        self.edge_padding_impl(pixel_count, options)?;
+
+        Ok(self)
+    }
+}
+
+impl ImageBuffer {
+    #[inline]
+    fn fill_holes_push_pull_ffi(&mut self, source: &ImageBuffer, options: &Options) -> bool {
+        let mut is_ok = MaybeUninit::<bool>::uninit();
+
+        unsafe {
+            oiio_ImageBufAlgo_fillholes_pushpull(
+                self.as_raw_ptr_mut(),
+                source.as_raw_ptr(),
+                options.region.clone().into(),
+                options.thread_count as _,
+                &raw mut is_ok as _,
+            );
+
+            is_ok.assume_init()
+        }
+    }
+
+    #[named]
+    fn edge_padding_impl(&mut self, pixel_count: u32, options: &Options) -> Result<()> {
+        let alpha_channel_index = self
+            .image_spec()
+            .alpha_channel_index
+            .ok_or_else(|| anyhow!("[fn {}] Image has no alpha channel", function_name!()))?
+            as usize;
+
+        let channel_count = self.channel_count() as usize;
+
+        let mut bounds = match &options.region {
+            Region::All => self.data_window(),
+            Region::Bounds(bounds) => bounds.clone(),
+        };
+        bounds.set_channel(0..channel_count as _);
+
+        let region = Region::Bounds(bounds.clone());
+        let mut pixels: Vec<f32> = self.pixels(&region)?;
+
+        let width = bounds.width() as usize;
+        let height = bounds.height() as usize;
+        let slice_size = width * height;
+
+        // Pixels that are either part of the image or were already filled.
+        let mut is_filled = pixels
+            .chunks_exact(channel_count)
+            .map(|pixel| 0.0 < pixel[alpha_channel_index])
+            .collect::<Vec<_>>();
+
+        let mut color = vec![0.0f32; channel_count];
+
+        for _ in 0..pixel_count {
+            let mut filled_this_pass = Vec::new();
+
+            for index in (0..is_filled.len()).filter(|&index| !is_filled[index]) {
+                let slice = index / slice_size * slice_size;
+                let x = (index % slice_size % width) as isize;
+                let y = (index % slice_size / width) as isize;
+
+                color.fill(0.0);
+                let mut neighbor_count = 0;
+
+                for (dx, dy) in (-1..=1)
+                    .flat_map(|dy| (-1..=1).map(move |dx| (dx, dy)))
+                    .filter(|&offset| (0, 0) != offset)
+                {
+                    let (nx, ny) = (x + dx, y + dy);
+                    if nx < 0 || ny < 0 || width as isize <= nx || height as isize <= ny {
+                        continue;
+                    }
+
+                    let neighbor = slice + ny as usize * width + nx as usize;
+                    if !is_filled[neighbor] {
+                        continue;
+                    }
+
+                    let pixel = &pixels[neighbor * channel_count..][..channel_count];
+                    let alpha = pixel[alpha_channel_index];
+
+                    color
+                        .iter_mut()
+                        .zip(pixel)
+                        .for_each(|(color, value)| *color += value / alpha);
+                    neighbor_count += 1;
+                }
+
+                if 0 < neighbor_count {
+                    color
+                        .iter_mut()
+                        .for_each(|color| *color /= neighbor_count as f32);
+                    color[alpha_channel_index] = 1.0;
+
+                    filled_this_pass.push((index, color.clone()));
+                }
+            }
+
+            if filled_this_pass.is_empty() {
+                break;
+            }
+
+            // Only update after the pass so a pass grows the filled area by
+            // exactly one pixel.
+            for (index, color) in filled_this_pass {
+                pixels[index * channel_count..][..channel_count].copy_from_slice(&color);
+                is_filled[index] = true;
+            }
+        }
+
+        self.set_pixels(&pixels, &region)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn fill_holes_push_pull() -> Result<()> {
+        let mut image_buffer =
+            ImageBuffer::from_file(Utf8Path::new("assets/j0.3toD__F16_RGBA.exr"))?;
+
+        image_buffer.fill_holes_push_pull()?;
+        assert!(image_buffer.is_constant_channel(3, 1.0, 1.0e-3));
+
+        image_buffer.write(Utf8Path::new("target/fill_holes_push_pull.exr"))?;
+
+        // A single opaque pixel fills the whole image.
+        let mut image_buffer =
+            ImageBuffer::from_zero(&Bounds::new(0..16, 0..16, 0..1, Some(0..4)))?;
+        image_buffer.set_pixel(8, 8, None, &[0.25, 0.5, 0.75, 1.0]);
+
+        image_buffer.fill_holes_push_pull()?;
+
+        assert!(image_buffer.is_constant_channel(3, 1.0, 1.0e-6));
+        let hole = image_buffer.get_pixel(0, 0, None, WrapMode::Black);
+        assert!(hole[..3].iter().all(|value| 0.0 < *value));
+
+        Ok(())
+    }
+
+    #[test]
+    fn edge_padding() -> Result<()> {
+        let mut image_buffer =
+            ImageBuffer::from_zero(&Bounds::new(0..16, 0..16, 0..1, Some(0..4)))?;
+        image_buffer.set_pixel(8, 8, None, &[0.25, 0.5, 0.75, 0.5]);
+
+        image_buffer.edge_padding(2)?;
+
+        let pixels: Vec<f32> = image_buffer.pixels(&Region::All)?;
+        let pixel = |x: usize, y: usize| &pixels[(y * 16 + x) * 4..][..4];
+
+        // Unpremultiplied color, made opaque.
+        assert_eq!(&[0.5, 1.0, 1.5, 1.0], pixel(10, 6));
+        // Out of reach.
+        assert_eq!(&[0.0, 0.0, 0.0, 0.0], pixel(11, 8));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,334 @@
+use crate::{algorithms::*, *};
+use anyhow::{anyhow, Result};
+
+/// A group of channels that share a name prefix.
+///
+/// In a multichannel OpenEXR file the channels of e.g. a *diffuse* AOV are
+/// named `diffuse.R`, `diffuse.G` and `diffuse.B`. The layer name is the part
+/// before the last `.`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Layer {
+    /// The name of the layer.
+    ///
+    /// This is empty for channels without a prefix, e.g. the main `R`, `G`,
+    /// `B`, `A` and `Z` channels.
+    pub name: String,
+    /// The indices of the channels of the layer in the image, in order.
+    pub channel_index: Vec<u32>,
+    /// The names of the channels, with the layer name stripped.
+    pub channel_name: Vec<Ustr>,
+}
+
+/// # Layers
+///
+/// Channels are grouped into [`Layer`]s by their name prefix.
+///
+/// When a layer is extracted, its channels are ordered following the OpenEXR
+/// conventions:
+///
+/// 1. Color: `R`, `G`, `B` (or `X`, `Y`, `Z` for vectors like normals).
+/// 2. Alpha: `A`, then the per-channel alphas `AR`, `AG`, `AB`.
+/// 3. Depth: `Z`, `ZBack`.
+/// 4. Everything else, in the order found in the image.
+///
+/// `A` is marked as the alpha and `Z` as the depth channel of the result,
+/// unless the layer holds a vector.
+impl ImageBuffer {
+    /// Returns the layers of this image, in the order of their first channel.
+    pub fn layers(&self) -> Vec<Layer> {
+        let mut layers = Vec::<Layer>::new();
+
+        for (index, channel_name) in self.image_spec().channel_name.iter().enumerate() {
+            let (name, channel_name) = split_channel_name(channel_name);
+
+            let layer = match layers.iter_mut().position(|layer| layer.name == name) {
+                Some(position) => &mut layers[position],
+                None => {
+                    layers.push(Layer {
+                        name: name.to_string(),
+                        ..Default::default()
+                    });
+                    layers.last_mut().unwrap()
+                }
+            };
+
+            layer.channel_index.push(index as _);
+            layer.channel_name.push(ustr(channel_name));
+        }
+
+        layers
+    }
+
+    /// Returns the names of the layers of this image.
+    ///
+    /// The name of the layer holding the channels without a prefix is the
+    /// empty string.
+    pub fn layer_names(&self) -> Vec<String> {
+        self.layers().into_iter().map(|layer| layer.name).collect()
+    }
+
+    /// Create an image from the channels of layer `name` of `source`.
+    ///
+    /// The layer name is stripped from the channel names, i.e. `diffuse.R`
+    /// becomes `R`.
+    #[named]
+    pub fn from_layer(source: &ImageBuffer, name: &str) -> Result<Self> {
+        let layer = source
+            .layers()
+            .into_iter()
+            .find(|layer| layer.name == name)
+            .ok_or_else(|| anyhow!("[fn {}] No layer named '{name}'", function_name!()))?;
+
+        let is_vector = is_vector(&layer.channel_name);
+
+        let mut channels = layer
+            .channel_index
+            .into_iter()
+            .zip(layer.channel_name)
+            .collect::<Vec<_>>();
+        // Stable, so unknown channels keep their order.
+        channels.sort_by_key(|(_, channel_name)| channel_rank(channel_name, is_vector));
+
+        let mut image_buffer = ImageBuffer::new();
+        image_buffer.replace_by_channels_with(
+            source,
+            &channels
+                .iter()
+                .map(|(index, _)| Channel::Index(*index, None))
+                .collect::<Vec<_>>(),
+            &ChannelsOptions {
+                shuffle_names: true,
+                ..Default::default()
+            },
+        )?;
+
+        image_buffer.set_channel_names(
+            &channels
+                .iter()
+                .map(|(_, channel_name)| channel_name.to_string())
+                .collect::<Vec<_>>(),
+        );
+
+        Ok(image_buffer)
+    }
+
+    /// Insert the channels of `layer` as layer `name`.
+    ///
+    /// Any channels of an existing layer of that name are removed first. The
+    /// new channels are appended after all other channels and their names
+    /// are prefixed with `name`.
+    ///
+    /// If the data windows of both images differ, the result covers their
+    /// union.
+    pub fn set_layer(&mut self, name: &str, layer: &ImageBuffer) -> Result<&mut Self> {
+        let (channels, mut channel_names) = self.channels_except_layer(name);
+
+        channel_names.extend(
+            layer
+                .image_spec()
+                .channel_name
+                .iter()
+                .map(|channel_name| prefixed_channel_name(name, channel_name)),
+        );
+
+        let mut image_buffer = if channels.is_empty() {
+            layer.clone()
+        } else {
+            let mut image_buffer = ImageBuffer::new();
+            image_buffer
+                .replace_by_channels_with(
+                    self,
+                    &channels,
+                    &ChannelsOptions {
+                        shuffle_names: true,
+                        ..Default::default()
+                    },
+                )?
+                .channel_append(layer)?;
+
+            image_buffer
+        };
+
+        image_buffer.set_channel_names(&channel_names);
+        *self = image_buffer;
+
+        Ok(self)
+    }
+
+    /// Remove all channels of layer `name`.
+    ///
+    /// If these were all the channels of the image, it is
+    /// [`reset()`](ImageBuffer::reset).
+    #[named]
+    pub fn remove_layer(&mut self, name: &str) -> Result<&mut Self> {
+        if !self.layers().iter().any(|layer| layer.name == name) {
+            return Err(anyhow!("[fn {}] No layer named '{name}'", function_name!()));
+        }
+
+        let (channels, channel_names) = self.channels_except_layer(name);
+
+        if channels.is_empty() {
+            self.reset();
+        } else {
+            self.channels_with(
+                &channels,
+                &ChannelsOptions {
+                    shuffle_names: true,
+                    ..Default::default()
+                },
+            )?;
+            self.set_channel_names(&channel_names);
+        }
+
+        Ok(self)
+    }
+}
+
+impl ImageBuffer {
+    /// Returns the channels (and their names) not belonging to layer `name`.
+    fn channels_except_layer(&self, name: &str) -> (Vec<Channel<'static>>, Vec<String>) {
+        self.image_spec()
+            .channel_name
+            .iter()
+            .enumerate()
+            .filter(|(_, channel_name)| name != split_channel_name(channel_name).0)
+            .map(|(index, channel_name)| {
+                (Channel::Index(index as _, None), channel_name.to_string())
+            })
+            .unzip()
+    }
+
+    /// Renames the channels and updates the alpha and depth channel indices.
+    fn set_channel_names(&mut self, channel_names: &[String]) {
+        let is_vector = is_vector(channel_names);
+
+        let alpha_channel_index = channel_names.iter().position(|name| "A" == name.as_str());
+        let z_channel_index = channel_names
+            .iter()
+            .position(|name| "Z" == name.as_str())
+            .filter(|_| !is_vector);
+
+        let mut image_spec_ptr = MaybeUninit::<*mut oiio_ImageSpec_t>::uninit();
+
+        unsafe {
+            oiio_ImageBuf_specmod(self.as_raw_ptr_mut(), &raw mut image_spec_ptr as _);
+            let image_spec_ptr = image_spec_ptr.assume_init();
+
+            oiio_ImageSpec_clear_and_reserve_channelnames(image_spec_ptr, channel_names.len());
+            for channel_name in channel_names {
+                oiio_ImageSpec_push_channelname(
+                    image_spec_ptr,
+                    OiioString::new(channel_name).as_raw_ptr(),
+                );
+            }
+
+            oiio_ImageSpec_set_alpha_channel(
+                image_spec_ptr,
+                alpha_channel_index.map(|index| index as _).unwrap_or(-1),
+            );
+            oiio_ImageSpec_set_z_channel(
+                image_spec_ptr,
+                z_channel_index.map(|index| index as _).unwrap_or(-1),
+            );
+        }
+    }
+}
+
+/// Splits `diffuse.R` into `("diffuse", "R")` and `R` into `("", "R")`.
+fn split_channel_name(channel_name: &str) -> (&str, &str) {
+    channel_name.rsplit_once('.').unwrap_or(("", channel_name))
+}
+
+fn prefixed_channel_name(layer_name: &str, channel_name: &str) -> String {
+    if layer_name.is_empty() {
+        channel_name.to_string()
+    } else {
+        format!("{layer_name}.{channel_name}")
+    }
+}
+
+/// A layer with `X` or `Y` channels holds a vector (e.g. a normal or
+/// position) and its `Z` channel is not depth.
+fn is_vector(channel_names: &[impl AsRef<str>]) -> bool {
+    channel_names
+        .iter()
+        .any(|name| matches!(name.as_ref(), "X" | "Y"))
+}
+
+fn channel_rank(channel_name: &str, is_vector: bool) -> u8 {
+    match (channel_name, is_vector) {
+        ("R", _) | ("X", true) => 0,
+        ("G", _) | ("Y", true) => 1,
+        ("B", _) | ("Z", true) => 2,
+        ("A", _) => 3,
+        ("AR", _) => 4,
+        ("AG", _) => 5,
+        ("AB", _) => 6,
+        ("Z", false) => 7,
+        ("ZBack", _) => 8,
+        _ => 9,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{algorithms::*, *};
+
+    #[test]
+    fn layers() -> Result<()> {
+        let mut image_buffer = ImageBuffer::from_fill(
+            &[0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0],
+            &Bounds::new(0..4, 0..4, 0..1, Some(0..8)),
+        )?;
+        image_buffer.set_channel_names(
+            &[
+                "R",
+                "G",
+                "B",
+                "A",
+                "diffuse.B",
+                "diffuse.G",
+                "diffuse.R",
+                "depth.Z",
+            ]
+            .map(String::from),
+        );
+
+        assert_eq!(vec!["", "diffuse", "depth"], image_buffer.layer_names());
+
+        let diffuse = ImageBuffer::from_layer(&image_buffer, "diffuse")?;
+        assert_eq!(
+            vec![ustr("R"), ustr("G"), ustr("B")],
+            diffuse.image_spec().channel_name
+        );
+        assert_eq!(
+            vec![6.0, 5.0, 4.0],
+            diffuse.get_pixel(0, 0, None, WrapMode::Black)
+        );
+
+        let depth = ImageBuffer::from_layer(&image_buffer, "depth")?;
+        assert_eq!(Some(0), depth.image_spec().z_channel_index);
+
+        assert!(ImageBuffer::from_layer(&image_buffer, "specular").is_err());
+
+        image_buffer.set_layer("specular", &diffuse)?;
+        assert_eq!(11, image_buffer.channel_count());
+        assert_eq!(Some(3), image_buffer.image_spec().alpha_channel_index);
+        assert_eq!(
+            ustr("specular.B"),
+            image_buffer.image_spec().channel_name[10]
+        );
+
+        // Replace.
+        image_buffer.set_layer("diffuse", &depth)?;
+        assert_eq!(
+            vec!["", "depth", "specular", "diffuse"],
+            image_buffer.layer_names()
+        );
+
+        image_buffer.remove_layer("specular")?;
+        assert_eq!(6, image_buffer.channel_count());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,121 @@
+use crate::{algorithms::*, *};
+
+/// # Median Filter
+///
+/// Replace each pixel with the median value of each channel within a
+/// `width` × `height` window around it.
+///
+/// This removes salt-and-pepper noise while preserving edges better than a
+/// blur. Use the channel range of `options.region` to restrict it to
+/// specific channels.
+impl ImageBuffer {
+    #[named]
+    pub fn replace_by_median_filter(
+        &mut self,
+        source: &ImageBuffer,
+        width: u32,
+        height: u32,
+    ) -> Result<&mut Self> {
+        let is_ok = self.median_filter_ffi(source, width, height, &Options::default());
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+
+    #[named]
+    pub fn replace_by_median_filter_with(
+        &mut self,
+        source: &ImageBuffer,
+        width: u32,
+        height: u32,
+        options: &Options,
+    ) -> Result<&mut Self> {
+        let is_ok = self.median_filter_ffi(source, width, height, options);
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+
+    #[named]
+    pub fn median_filter(&mut self, width: u32, height: u32) -> Result<&mut Self> {
+        let mut image_buffer = ImageBuffer::new();
+        let is_ok = image_buffer.median_filter_ffi(self, width, height, &Options::default());
+        *self = image_buffer;
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+
+    #[named]
+    pub fn median_filter_with(
+        &mut self,
+        width: u32,
+        height: u32,
+        options: &Options,
+    ) -> Result<&mut Self> {
+        let mut image_buffer = ImageBuffer::new();
+        let is_ok = image_buffer.median_filter_ffi(self, width, height, options);
+        *self = image_buffer;
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+}
+
+impl ImageBuffer {
+    #[inline]
+    fn median_filter_ffi(
+        &mut self,
+        source: &ImageBuffer,
+        width: u32,
+        height: u32,
+        options: &Options,
+    ) -> bool {
+        let mut is_ok = MaybeUninit::<bool>::uninit();
+
+        unsafe {
+            oiio_ImageBufAlgo_median_filter(
+                self.as_raw_ptr_mut(),
+                source.as_raw_ptr(),
+                width as _,
+                height as _,
+                options.region.clone().into(),
+                options.thread_count as _,
+                &raw mut is_ok as _,
+            );
+
+            is_ok.assume_init()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{algorithms::*, *};
+
+    #[test]
+    fn median_filter() -> Result<()> {
+        let mut image_buffer =
+            ImageBuffer::from_file(Utf8Path::new("assets/j0.3toD__F16_RGBA.exr"))?;
+
+        image_buffer.noise(NoiseType::Salt {
+            salt: 1.0,
+            percentage_salted: 0.01,
+        })?;
+        image_buffer.median_filter(3, 3)?;
+
+        image_buffer.write(Utf8Path::new("target/median_filter.exr"))?;
+
+        // A single salted pixel in a constant image is removed.
+        let mut image_buffer = ImageBuffer::from_fill(
+            &[0.25, 0.5, 0.75],
+            &Bounds::new(0..8, 0..8, 0..1, Some(0..3)),
+        )?;
+        image_buffer.set_pixel(4, 4, None, &[1.0, 1.0, 1.0]);
+
+        image_buffer.median_filter(3, 3)?;
+
+        assert_eq!(
+            vec![0.25, 0.5, 0.75],
+            image_buffer.get_pixel(4, 4, None, WrapMode::Black)
+        );
+
+        Ok(())
+    }
+}
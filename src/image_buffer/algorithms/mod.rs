@@ -74,7 +74,7 @@
 //! ```
 //!
 //! For a small set of functions, there are only input images, and no image
-//! outputs (e.g., `is_monochrome()`).
+//! outputs (e.g., [`is_monochrome()`](ImageBuffer::is_monochrome)).
 //!
 //! ## Region (of Interest)
 //!
@@ -131,6 +131,9 @@
 //! application threads.
 use crate::*;
 
+pub mod channel_append;
+pub mod channel_sum;
+pub use channel_sum::*;
 pub mod channels;
 pub use channels::*;
 pub mod checker;
@@ -138,20 +141,41 @@ pub use checker::*;
 pub mod color_convert;
 pub use color_convert::*;
 pub mod compare;
+pub use compare::*;
+pub mod composite;
+pub use composite::*;
+pub mod contrast_remap;
+pub use contrast_remap::*;
 pub mod convolve;
 pub mod crop;
 pub mod cut;
+pub mod fft;
 pub mod fill;
+pub mod fill_holes;
 pub mod filter;
 pub use filter::*;
 pub mod kernel;
 pub use kernel::*;
+pub mod layers;
+pub use layers::*;
 pub mod invert;
+pub mod median_filter;
+pub mod mosaic;
+pub use mosaic::*;
+pub mod mul;
+pub mod morphology;
 pub mod noise;
+pub mod ocio_display;
+pub use ocio_display::*;
 pub mod over;
 pub use noise::*;
+pub mod paste;
+pub use paste::*;
 pub mod pixel_hash;
+pub mod predicates;
 pub mod premult;
+pub mod render;
+pub use render::*;
 pub mod render_text;
 pub use render_text::*;
 pub mod re_orient;
@@ -159,8 +183,12 @@ pub mod resize;
 pub use resize::*;
 pub mod rotate;
 pub use rotate::*;
+pub mod saturate;
+pub use saturate::*;
 pub mod transform;
 pub use transform::*;
+pub mod unsharp_mask;
+pub use unsharp_mask::*;
 pub mod warp;
 pub use warp::*;
 pub mod zero;
@@ -176,3 +204,35 @@ pub struct Options {
     /// in the [module@algorithms] module.
     pub thread_count: u16,
 }
+
+impl ImageBuffer {
+    /// Shared FFI call for all operations that only take a source image, a
+    /// region and a thread count.
+    #[inline]
+    pub(crate) fn source_region_ffi(
+        &mut self,
+        function: unsafe extern "C" fn(
+            *mut oiio_ImageBuf_t,
+            *const oiio_ImageBuf_t,
+            oiio_ROI_t,
+            core::ffi::c_int,
+            *mut bool,
+        ) -> core::ffi::c_int,
+        source: &ImageBuffer,
+        options: &Options,
+    ) -> bool {
+        let mut is_ok = MaybeUninit::<bool>::uninit();
+
+        unsafe {
+            function(
+                self.as_raw_ptr_mut(),
+                source.as_raw_ptr(),
+                options.region.clone().into(),
+                options.thread_count as _,
+                &raw mut is_ok as _,
+            );
+
+            is_ok.assume_init()
+        }
+    }
+}
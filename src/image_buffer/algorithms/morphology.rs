@@ -0,0 +1,280 @@
+use crate::{algorithms::*, *};
+
+/// # Dilate
+///
+/// Replace each pixel with the maximum value of each channel within a
+/// `width` × `height` window around it.
+///
+/// This grows bright regions (e.g. the opaque parts of a matte) and shrinks
+/// dark ones. Use the channel range of `options.region` to restrict it to
+/// e.g. the alpha channel.
+impl ImageBuffer {
+    #[named]
+    pub fn replace_by_dilate(
+        &mut self,
+        source: &ImageBuffer,
+        width: u32,
+        height: u32,
+    ) -> Result<&mut Self> {
+        let is_ok = self.dilate_ffi(source, width, height, &Options::default());
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+
+    #[named]
+    pub fn replace_by_dilate_with(
+        &mut self,
+        source: &ImageBuffer,
+        width: u32,
+        height: u32,
+        options: &Options,
+    ) -> Result<&mut Self> {
+        let is_ok = self.dilate_ffi(source, width, height, options);
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+
+    #[named]
+    pub fn dilate(&mut self, width: u32, height: u32) -> Result<&mut Self> {
+        let mut image_buffer = ImageBuffer::new();
+        let is_ok = image_buffer.dilate_ffi(self, width, height, &Options::default());
+        *self = image_buffer;
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+
+    #[named]
+    pub fn dilate_with(&mut self, width: u32, height: u32, options: &Options) -> Result<&mut Self> {
+        let mut image_buffer = ImageBuffer::new();
+        let is_ok = image_buffer.dilate_ffi(self, width, height, options);
+        *self = image_buffer;
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+}
+
+/// # Erode
+///
+/// Replace each pixel with the minimum value of each channel within a
+/// `width` × `height` window around it.
+///
+/// This shrinks bright regions (e.g. the opaque parts of a matte) and grows
+/// dark ones. Use the channel range of `options.region` to restrict it to
+/// e.g. the alpha channel.
+impl ImageBuffer {
+    #[named]
+    pub fn replace_by_erode(
+        &mut self,
+        source: &ImageBuffer,
+        width: u32,
+        height: u32,
+    ) -> Result<&mut Self> {
+        let is_ok = self.erode_ffi(source, width, height, &Options::default());
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+
+    #[named]
+    pub fn replace_by_erode_with(
+        &mut self,
+        source: &ImageBuffer,
+        width: u32,
+        height: u32,
+        options: &Options,
+    ) -> Result<&mut Self> {
+        let is_ok = self.erode_ffi(source, width, height, options);
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+
+    #[named]
+    pub fn erode(&mut self, width: u32, height: u32) -> Result<&mut Self> {
+        let mut image_buffer = ImageBuffer::new();
+        let is_ok = image_buffer.erode_ffi(self, width, height, &Options::default());
+        *self = image_buffer;
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+
+    #[named]
+    pub fn erode_with(&mut self, width: u32, height: u32, options: &Options) -> Result<&mut Self> {
+        let mut image_buffer = ImageBuffer::new();
+        let is_ok = image_buffer.erode_ffi(self, width, height, options);
+        *self = image_buffer;
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+}
+
+/// # Morphological Open
+///
+/// [Erode](ImageBuffer::erode), then [dilate](ImageBuffer::dilate) with the
+/// same window size.
+///
+/// This removes bright specks smaller than the window while leaving larger
+/// bright regions mostly unchanged.
+impl ImageBuffer {
+    pub fn replace_by_morphological_open(
+        &mut self,
+        source: &ImageBuffer,
+        width: u32,
+        height: u32,
+    ) -> Result<&mut Self> {
+        self.replace_by_erode(source, width, height)?
+            .dilate(width, height)
+    }
+
+    pub fn replace_by_morphological_open_with(
+        &mut self,
+        source: &ImageBuffer,
+        width: u32,
+        height: u32,
+        options: &Options,
+    ) -> Result<&mut Self> {
+        self.replace_by_erode_with(source, width, height, options)?
+            .dilate_with(width, height, options)
+    }
+
+    pub fn morphological_open(&mut self, width: u32, height: u32) -> Result<&mut Self> {
+        self.erode(width, height)?.dilate(width, height)
+    }
+
+    pub fn morphological_open_with(
+        &mut self,
+        width: u32,
+        height: u32,
+        options: &Options,
+    ) -> Result<&mut Self> {
+        self.erode_with(width, height, options)?
+            .dilate_with(width, height, options)
+    }
+}
+
+/// # Morphological Close
+///
+/// [Dilate](ImageBuffer::dilate), then [erode](ImageBuffer::erode) with the
+/// same window size.
+///
+/// This fills dark holes smaller than the window while leaving larger dark
+/// regions mostly unchanged.
+impl ImageBuffer {
+    pub fn replace_by_morphological_close(
+        &mut self,
+        source: &ImageBuffer,
+        width: u32,
+        height: u32,
+    ) -> Result<&mut Self> {
+        self.replace_by_dilate(source, width, height)?
+            .erode(width, height)
+    }
+
+    pub fn replace_by_morphological_close_with(
+        &mut self,
+        source: &ImageBuffer,
+        width: u32,
+        height: u32,
+        options: &Options,
+    ) -> Result<&mut Self> {
+        self.replace_by_dilate_with(source, width, height, options)?
+            .erode_with(width, height, options)
+    }
+
+    pub fn morphological_close(&mut self, width: u32, height: u32) -> Result<&mut Self> {
+        self.dilate(width, height)?.erode(width, height)
+    }
+
+    pub fn morphological_close_with(
+        &mut self,
+        width: u32,
+        height: u32,
+        options: &Options,
+    ) -> Result<&mut Self> {
+        self.dilate_with(width, height, options)?
+            .erode_with(width, height, options)
+    }
+}
+
+impl ImageBuffer {
+    #[inline]
+    fn dilate_ffi(
+        &mut self,
+        source: &ImageBuffer,
+        width: u32,
+        height: u32,
+        options: &Options,
+    ) -> bool {
+        let mut is_ok = MaybeUninit::<bool>::uninit();
+
+        unsafe {
+            oiio_ImageBufAlgo_dilate(
+                self.as_raw_ptr_mut(),
+                source.as_raw_ptr(),
+                width as _,
+                height as _,
+                options.region.clone().into(),
+                options.thread_count as _,
+                &raw mut is_ok as _,
+            );
+
+            is_ok.assume_init()
+        }
+    }
+
+    #[inline]
+    fn erode_ffi(
+        &mut self,
+        source: &ImageBuffer,
+        width: u32,
+        height: u32,
+        options: &Options,
+    ) -> bool {
+        let mut is_ok = MaybeUninit::<bool>::uninit();
+
+        unsafe {
+            oiio_ImageBufAlgo_erode(
+                self.as_raw_ptr_mut(),
+                source.as_raw_ptr(),
+                width as _,
+                height as _,
+                options.region.clone().into(),
+                options.thread_count as _,
+                &raw mut is_ok as _,
+            );
+
+            is_ok.assume_init()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn morphology() -> Result<()> {
+        let bounds = Bounds::new(0..32, 0..32, 0..1, Some(0..1));
+
+        // A single bright pixel.
+        let mut image_buffer = ImageBuffer::from_zero(&bounds)?;
+        image_buffer.set_pixel(16, 16, None, &[1.0]);
+
+        let mut dilated = image_buffer.clone();
+        dilated.dilate(3, 3)?;
+        assert_eq!(
+            Bounds::new(15..18, 15..18, 0..1, Some(0..1)),
+            dilated.nonzero_region()
+        );
+
+        // Opening removes features smaller than the window.
+        image_buffer.morphological_open(3, 3)?;
+        assert!(image_buffer.nonzero_region().is_empty());
+
+        // Closing fills holes smaller than the window.
+        let mut image_buffer = ImageBuffer::from_fill(&[1.0], &bounds)?;
+        image_buffer.set_pixel(16, 16, None, &[0.0]);
+        image_buffer.morphological_close(3, 3)?;
+        assert_eq!(Some(vec![1.0]), image_buffer.is_constant_color(0.0));
+
+        Ok(())
+    }
+}
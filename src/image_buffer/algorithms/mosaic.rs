@@ -0,0 +1,163 @@
+use crate::{algorithms::*, *};
+
+/// Optional parameters for [`ImageBuffer`]'s
+/// [`from_mosaic_with()`](ImageBuffer::from_mosaic_with) method.
+#[derive(Clone, Default)]
+pub struct MosaicOptions {
+    /// The gap between two neighboring cells in pixels.
+    pub gap: u32,
+    /// The color of the gaps and of the parts of the cells not covered by
+    /// their image. Defaults to black (zero in all channels).
+    pub background: Vec<f32>,
+    /// See the [Multithreading](module@algorithms#multithreading) section
+    /// in the [module@algorithms] module.
+    pub thread_count: u16,
+}
+
+/// # Mosaic
+///
+/// Create a new image that contains the given `images` arranged in a grid with
+/// `column_count` columns, left to right and top to bottom.
+///
+/// All cells have the size of the largest image. Each image's data window is
+/// placed at the top left corner of its cell, regardless of where the data
+/// window is located in the image. The result's data window starts at the
+/// origin and has as many channels as the image with the most channels.
+///
+/// This is useful to create contact sheets or to assemble tiles rendered to
+/// separate images back into a full frame.
+impl ImageBuffer {
+    pub fn from_mosaic(images: &[&ImageBuffer], column_count: u32) -> Result<Self> {
+        Self::from_mosaic_with(images, column_count, &MosaicOptions::default())
+    }
+
+    #[named]
+    pub fn from_mosaic_with(
+        images: &[&ImageBuffer],
+        column_count: u32,
+        options: &MosaicOptions,
+    ) -> Result<Self> {
+        if images.is_empty() || 0 == column_count {
+            return Err(anyhow!(
+                "[fn {}] At least one image and one column are required",
+                function_name!()
+            ));
+        }
+
+        let column_count = column_count.min(images.len() as _);
+        let row_count = (images.len() as u32).div_ceil(column_count);
+
+        let channel_count = images
+            .iter()
+            .map(|image| image.channel_count())
+            .max()
+            .unwrap_or(0);
+
+        if 0 == channel_count {
+            return Err(anyhow!(
+                "[fn {}] The images have no channels",
+                function_name!()
+            ));
+        }
+
+        let (cell_width, cell_height) = images.iter().fold((0, 0), |(width, height), image| {
+            let data_window = image.data_window();
+            (
+                width.max(data_window.width()),
+                height.max(data_window.height()),
+            )
+        });
+
+        let background = if options.background.is_empty() {
+            vec![0.0]
+        } else {
+            options.background.clone()
+        };
+
+        let mut image_buffer = ImageBuffer::from_fill_with(
+            &background,
+            &Bounds::new(
+                0..(column_count * (cell_width + options.gap) - options.gap) as _,
+                0..(row_count * (cell_height + options.gap) - options.gap) as _,
+                0..1,
+                Some(0..channel_count as _),
+            ),
+            options.thread_count,
+        )?;
+
+        for (index, image) in images.iter().enumerate() {
+            let column = index as u32 % column_count;
+            let row = index as u32 / column_count;
+
+            image_buffer.paste_with(
+                (column * (cell_width + options.gap)) as _,
+                (row * (cell_height + options.gap)) as _,
+                image,
+                &PasteOptions {
+                    thread_count: options.thread_count,
+                    ..Default::default()
+                },
+            )?;
+        }
+
+        Ok(image_buffer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{algorithms::*, *};
+
+    #[test]
+    fn mosaic() -> Result<()> {
+        let image_buffer = ImageBuffer::from_file(Utf8Path::new("assets/j0.3toD__F16_RGBA.exr"))?;
+        let data_window = image_buffer.data_window();
+
+        let mut flipped = image_buffer.clone();
+        flipped.flip()?;
+
+        let mosaic = ImageBuffer::from_mosaic_with(
+            &[&image_buffer, &flipped, &flipped],
+            2,
+            &MosaicOptions {
+                gap: 4,
+                ..Default::default()
+            },
+        )?;
+
+        let mosaic_window = mosaic.data_window();
+        assert_eq!(2 * data_window.width() + 4, mosaic_window.width());
+        assert_eq!(2 * data_window.height() + 4, mosaic_window.height());
+
+        mosaic.write(Utf8Path::new("target/mosaic.exr"))?;
+
+        // Three 4×2 cells in two columns with a gap of 1.
+        let bounds = Bounds::new(0..4, 0..2, 0..1, Some(0..3));
+        let red = ImageBuffer::from_fill(&[1.0, 0.0, 0.0], &bounds)?;
+        let green = ImageBuffer::from_fill(&[0.0, 1.0, 0.0], &bounds)?;
+        let blue = ImageBuffer::from_fill(&[0.0, 0.0, 1.0], &bounds)?;
+
+        let mosaic = ImageBuffer::from_mosaic_with(
+            &[&red, &green, &blue],
+            2,
+            &MosaicOptions {
+                gap: 1,
+                background: vec![0.5, 0.5, 0.5],
+                ..Default::default()
+            },
+        )?;
+
+        let pixel = |x, y| mosaic.get_pixel(x, y, None, WrapMode::Black);
+        assert_eq!(vec![1.0, 0.0, 0.0], pixel(3, 1));
+        assert_eq!(vec![0.5, 0.5, 0.5], pixel(4, 0));
+        assert_eq!(vec![0.0, 1.0, 0.0], pixel(5, 0));
+        assert_eq!(vec![0.5, 0.5, 0.5], pixel(0, 2));
+        assert_eq!(vec![0.0, 0.0, 1.0], pixel(0, 3));
+        // The last cell is not covered.
+        assert_eq!(vec![0.5, 0.5, 0.5], pixel(5, 3));
+
+        assert!(ImageBuffer::from_mosaic(&[&ImageBuffer::new()], 1).is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,88 @@
+use crate::{algorithms::*, *};
+
+/// # Multiply
+///
+/// Multiply two images, pixel by pixel and channel by channel.
+///
+/// Multiplying an [`fft()`](ImageBuffer::fft) result with a two-channel
+/// image that holds the same real weight in both channels scales each
+/// frequency by that weight, e.g. to apply a low-pass filter before the
+/// [`ifft()`](ImageBuffer::ifft).
+impl ImageBuffer {
+    #[named]
+    pub fn replace_by_mul(&mut self, a: &ImageBuffer, b: &ImageBuffer) -> Result<&mut Self> {
+        let is_ok = self.mul_ffi(a, b, &Options::default());
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+
+    #[named]
+    pub fn replace_by_mul_with(
+        &mut self,
+        a: &ImageBuffer,
+        b: &ImageBuffer,
+        options: &Options,
+    ) -> Result<&mut Self> {
+        let is_ok = self.mul_ffi(a, b, options);
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+
+    #[named]
+    pub fn mul(&mut self, other: &ImageBuffer) -> Result<&mut Self> {
+        let mut image_buffer = ImageBuffer::new();
+        let is_ok = image_buffer.mul_ffi(self, other, &Options::default());
+        *self = image_buffer;
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+
+    #[named]
+    pub fn mul_with(&mut self, other: &ImageBuffer, options: &Options) -> Result<&mut Self> {
+        let mut image_buffer = ImageBuffer::new();
+        let is_ok = image_buffer.mul_ffi(self, other, options);
+        *self = image_buffer;
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+}
+
+impl ImageBuffer {
+    #[inline]
+    fn mul_ffi(&mut self, a: &ImageBuffer, b: &ImageBuffer, options: &Options) -> bool {
+        let mut is_ok = MaybeUninit::<bool>::uninit();
+
+        unsafe {
+            oiio_ImageBufAlgo_mul(
+                self.as_raw_ptr_mut(),
+                a.as_raw_ptr(),
+                b.as_raw_ptr(),
+                options.region.clone().into(),
+                options.thread_count as _,
+                &raw mut is_ok as _,
+            );
+
+            is_ok.assume_init()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn mul() -> Result<()> {
+        let bounds = Bounds::new(0..4, 0..4, 0..1, Some(0..2));
+        let mut image_buffer = ImageBuffer::from_fill(&[0.5, 2.0], &bounds)?;
+
+        image_buffer.mul(&ImageBuffer::from_fill(&[3.0, 0.25], &bounds)?)?;
+
+        assert_eq!(
+            vec![1.5, 0.5],
+            image_buffer.get_pixel(0, 0, None, WrapMode::Black)
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,189 @@
+use crate::{algorithms::*, *};
+
+/// Optional parameters for [`ImageBuffer`]'s
+/// [`replace_by_ocio_display_with()`](ImageBuffer::replace_by_ocio_display_with),
+/// [`ocio_display_with()`](ImageBuffer::ocio_display_with) methods.
+pub struct OcioDisplayOptions<'a> {
+    /// The color space the pixels are in. If `None`, the color space of the
+    /// source image is used.
+    pub from_space: Option<&'a str>,
+    /// A comma-separated list of OCIO *looks* to apply before the display
+    /// transform.
+    pub looks: Option<&'a str>,
+    /// If `true` (the default), unpremultiply the image before the transform
+    /// and re-premultiply after it.
+    pub unpremultiply: bool,
+    /// Define an optional context via a key-value tuple (for example, a
+    /// shot-specific transform).
+    pub context: Option<(&'a str, &'a str)>,
+    pub config: Option<ColorConfig>,
+    pub region: Region,
+    pub thread_count: u16,
+}
+
+impl Default for OcioDisplayOptions<'_> {
+    fn default() -> Self {
+        Self {
+            from_space: None,
+            looks: None,
+            unpremultiply: true,
+            context: None,
+            config: None,
+            region: Region::default(),
+            thread_count: 0,
+        }
+    }
+}
+
+/// # OCIO Display Transform
+///
+/// Apply an OpenColorIO *display/view* transform to the pixels, i.e. prepare
+/// them for viewing on the given `display` through the given `view`.
+///
+/// If `display` or `view` are `None` the defaults of the color configuration
+/// are used.
+///
+/// ## For C++ Developers
+///
+/// The C++ name of this function is `ociodisplay()`.
+impl ImageBuffer {
+    #[named]
+    pub fn replace_by_ocio_display(
+        &mut self,
+        source: &ImageBuffer,
+        display: Option<&str>,
+        view: Option<&str>,
+    ) -> Result<&mut Self> {
+        let is_ok = self.ocio_display_ffi(source, display, view, &OcioDisplayOptions::default());
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+
+    #[named]
+    pub fn replace_by_ocio_display_with(
+        &mut self,
+        source: &ImageBuffer,
+        display: Option<&str>,
+        view: Option<&str>,
+        options: &OcioDisplayOptions,
+    ) -> Result<&mut Self> {
+        let is_ok = self.ocio_display_ffi(source, display, view, options);
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+
+    #[named]
+    pub fn ocio_display(&mut self, display: Option<&str>, view: Option<&str>) -> Result<&mut Self> {
+        let mut image_buffer = ImageBuffer::new();
+        let is_ok =
+            image_buffer.ocio_display_ffi(self, display, view, &OcioDisplayOptions::default());
+        *self = image_buffer;
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+
+    #[named]
+    pub fn ocio_display_with(
+        &mut self,
+        display: Option<&str>,
+        view: Option<&str>,
+        options: &OcioDisplayOptions,
+    ) -> Result<&mut Self> {
+        let mut image_buffer = ImageBuffer::new();
+        let is_ok = image_buffer.ocio_display_ffi(self, display, view, options);
+        *self = image_buffer;
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+}
+
+impl ImageBuffer {
+    #[inline]
+    fn ocio_display_ffi(
+        &mut self,
+        source: &ImageBuffer,
+        display: Option<&str>,
+        view: Option<&str>,
+        options: &OcioDisplayOptions,
+    ) -> bool {
+        let mut is_ok = MaybeUninit::<bool>::uninit();
+
+        unsafe {
+            oiio_ImageBufAlgo_ociodisplay(
+                self.as_raw_ptr_mut(),
+                source.as_raw_ptr(),
+                display
+                    .map_or(StringView::default(), StringView::from)
+                    .as_raw_ptr() as _,
+                view.map_or(StringView::default(), StringView::from)
+                    .as_raw_ptr() as _,
+                options
+                    .from_space
+                    .map_or(StringView::default(), StringView::from)
+                    .as_raw_ptr() as _,
+                options
+                    .looks
+                    .map_or(StringView::default(), StringView::from)
+                    .as_raw_ptr() as _,
+                options.unpremultiply,
+                options
+                    .context
+                    .map_or(StringView::default(), |c| StringView::from(c.0))
+                    .as_raw_ptr() as _,
+                options
+                    .context
+                    .map_or(StringView::default(), |c| StringView::from(c.1))
+                    .as_raw_ptr() as _,
+                options
+                    .config
+                    .as_ref()
+                    .map_or(ptr::null_mut(), |s| *s.read_arc()),
+                options.region.clone().into(),
+                options.thread_count as _,
+                &raw mut is_ok as _,
+            );
+
+            is_ok.assume_init()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{algorithms::*, *};
+
+    #[test]
+    fn ocio_display() -> Result<()> {
+        let mut image_buffer =
+            ImageBuffer::from_file(Utf8Path::new("assets/j0.3toD__F16_RGBA.exr"))?;
+
+        image_buffer.ocio_display(None, None)?;
+
+        image_buffer.write(Utf8Path::new("target/ocio_display.exr"))?;
+
+        // Linear mid-gray is encoded brighter for display, stays neutral and
+        // keeps its alpha.
+        let mut image_buffer =
+            ImageBuffer::from_fill(&[0.18, 0.18, 0.18, 1.0], &Bounds::new_2d(0..4, 0..4))?;
+
+        image_buffer.ocio_display_with(
+            None,
+            None,
+            &OcioDisplayOptions {
+                from_space: Some("lin_rec709"),
+                ..Default::default()
+            },
+        )?;
+
+        let color = image_buffer
+            .is_constant_color(1.0e-6)
+            .expect("A constant input stays constant");
+
+        assert!(0.35 < color[0] && color[0] < 0.55);
+        assert!((color[0] - color[1]).abs() < 1.0e-4);
+        assert!((color[0] - color[2]).abs() < 1.0e-4);
+        assert_eq!(1.0, color[3]);
+
+        Ok(())
+    }
+}
@@ -46,9 +46,81 @@ impl ImageBuffer {
     }
 }
 
+/// # Z-Over
+///
+/// Composite using the *z* (depth) channels of both images.
+///
+/// For each pixel, whichever of `foreground` or `background` is closer to the
+/// camera (has the smaller *z* value) is composited *over* the other one. The
+/// *z* channel indices are taken from the resp. [`ImageSpec`]s. It is an error
+/// if either image lacks a *z* channel.
+///
+/// If `z_zero_is_infinity` is `true`, a *z* value of `0.0` is treated as being
+/// infinitely far away (this is common for renderers that leave *z* at `0.0`
+/// for pixels with no geometry).
+impl ImageBuffer {
+    #[named]
+    pub fn replace_by_zover(
+        &mut self,
+        foreground: &ImageBuffer,
+        background: &ImageBuffer,
+        z_zero_is_infinity: bool,
+    ) -> Result<&mut Self> {
+        let is_ok = self.zover_ffi(
+            foreground,
+            background,
+            z_zero_is_infinity,
+            &Options::default(),
+        );
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+
+    #[named]
+    pub fn replace_by_zover_with(
+        &mut self,
+        foreground: &ImageBuffer,
+        background: &ImageBuffer,
+        z_zero_is_infinity: bool,
+        options: &Options,
+    ) -> Result<&mut Self> {
+        let is_ok = self.zover_ffi(foreground, background, z_zero_is_infinity, options);
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+
+    #[named]
+    pub fn zover(
+        &mut self,
+        foreground: &ImageBuffer,
+        z_zero_is_infinity: bool,
+    ) -> Result<&mut Self> {
+        let mut image_buffer = ImageBuffer::new();
+        let is_ok =
+            image_buffer.zover_ffi(foreground, self, z_zero_is_infinity, &Options::default());
+        *self = image_buffer;
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+
+    #[named]
+    pub fn zover_with(
+        &mut self,
+        foreground: &ImageBuffer,
+        z_zero_is_infinity: bool,
+        options: &Options,
+    ) -> Result<&mut Self> {
+        let mut image_buffer = ImageBuffer::new();
+        let is_ok = image_buffer.zover_ffi(foreground, self, z_zero_is_infinity, options);
+        *self = image_buffer;
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+}
+
 impl ImageBuffer {
     #[inline]
-    fn over_ffi(
+    pub(crate) fn over_ffi(
         &mut self,
         foreground: &ImageBuffer,
         background: &ImageBuffer,
@@ -69,6 +141,31 @@ impl ImageBuffer {
             is_ok.assume_init()
         }
     }
+
+    #[inline]
+    fn zover_ffi(
+        &mut self,
+        foreground: &ImageBuffer,
+        background: &ImageBuffer,
+        z_zero_is_infinity: bool,
+        options: &Options,
+    ) -> bool {
+        let mut is_ok = MaybeUninit::<bool>::uninit();
+
+        unsafe {
+            oiio_ImageBufAlgo_zover(
+                self.as_raw_ptr_mut(),
+                foreground.as_raw_ptr(),
+                background.as_raw_ptr(),
+                z_zero_is_infinity,
+                options.region.clone().into(),
+                options.thread_count as _,
+                &raw mut is_ok as _,
+            );
+
+            is_ok.assume_init()
+        }
+    }
 }
 
 #[cfg(test)]
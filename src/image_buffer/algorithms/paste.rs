@@ -0,0 +1,138 @@
+use crate::{algorithms::*, *};
+
+/// Optional parameters for [`ImageBuffer`]'s
+/// [`paste_with()`](ImageBuffer::paste_with) method.
+#[derive(Clone, Default)]
+pub struct PasteOptions {
+    /// The z coordinate in the destination the first slice of the source is
+    /// pasted to.
+    pub z: i32,
+    /// The channel in the destination the first channel of the source is
+    /// pasted to.
+    ///
+    /// E.g. to paste an alpha-only image into the alpha channel of an RGBA
+    /// image, set this to `3`.
+    pub channel: u32,
+    /// The region of the *source* that is pasted. By default this is the
+    /// source's data window and all of its channels.
+    ///
+    /// See the [Region of Interest](module@algorithms#region-of-interest)
+    /// section in the [module@algorithms] module.
+    pub region: Region,
+    /// See the [Multithreading](module@algorithms#multithreading) section
+    /// in the [module@algorithms] module.
+    pub thread_count: u16,
+}
+
+/// # Paste
+///
+/// Copy the pixels of `source` into this image, placing the first pixel of
+/// the source region at (`x`, `y`).
+///
+/// The position is given in absolute pixel coordinates of the destination,
+/// not relative to its [`data_window()`](ImageBuffer::data_window). To paste
+/// at the top left corner of the destination's data window, pass its
+/// [`x_start()`](Bounds::x_start) and [`y_start()`](Bounds::y_start). The
+/// same is true for the source: its pixels are taken from its own data
+/// window, wherever that is located.
+///
+/// Only the pixels (and channels) of the destination that overlap the pasted
+/// region are changed. If the destination is uninitialized, it is allocated to
+/// be just large enough to hold the pasted region.
+impl ImageBuffer {
+    #[named]
+    pub fn paste(&mut self, x: i32, y: i32, source: &ImageBuffer) -> Result<&mut Self> {
+        let is_ok = self.paste_ffi(x, y, source, &PasteOptions::default());
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+
+    #[named]
+    pub fn paste_with(
+        &mut self,
+        x: i32,
+        y: i32,
+        source: &ImageBuffer,
+        options: &PasteOptions,
+    ) -> Result<&mut Self> {
+        let is_ok = self.paste_ffi(x, y, source, options);
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+}
+
+impl ImageBuffer {
+    #[inline]
+    fn paste_ffi(&mut self, x: i32, y: i32, source: &ImageBuffer, options: &PasteOptions) -> bool {
+        let mut is_ok = MaybeUninit::<bool>::uninit();
+
+        unsafe {
+            oiio_ImageBufAlgo_paste(
+                self.as_raw_ptr_mut(),
+                x as _,
+                y as _,
+                options.z as _,
+                options.channel as _,
+                source.as_raw_ptr(),
+                options.region.clone().into(),
+                options.thread_count as _,
+                &raw mut is_ok as _,
+            );
+
+            is_ok.assume_init()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{algorithms::*, *};
+
+    #[test]
+    fn paste() -> Result<()> {
+        let mut image_buffer =
+            ImageBuffer::from_fill(&[0.0, 0.0, 0.0, 1.0], &Bounds::new_2d(0..64, 0..64))?;
+
+        // A source whose data window does not start at the origin.
+        let red = ImageBuffer::from_fill(&[1.0], &Bounds::new(8..16, 8..16, 0..1, Some(0..1)))?;
+
+        image_buffer.paste(32, 32, &red)?;
+
+        let region = Region::Bounds(Bounds::new_2d(32..40, 32..40));
+
+        assert_eq!(
+            Some(vec![1.0, 0.0, 0.0, 1.0]),
+            image_buffer.is_constant_color_with(
+                0.0,
+                &Options {
+                    region: region.clone(),
+                    ..Default::default()
+                }
+            )
+        );
+
+        // Paste the single channel source into the alpha channel.
+        image_buffer.paste_with(
+            32,
+            32,
+            &ImageBuffer::from_fill(&[0.5], &Bounds::new(0..8, 0..8, 0..1, Some(0..1)))?,
+            &PasteOptions {
+                channel: 3,
+                ..Default::default()
+            },
+        )?;
+
+        assert_eq!(
+            Some(vec![1.0, 0.0, 0.0, 0.5]),
+            image_buffer.is_constant_color_with(
+                0.0,
+                &Options {
+                    region,
+                    ..Default::default()
+                }
+            )
+        );
+
+        image_buffer.write(Utf8Path::new("target/paste.exr"))
+    }
+}
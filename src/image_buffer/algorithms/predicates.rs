@@ -0,0 +1,197 @@
+use crate::{algorithms::*, *};
+
+/// # Predicates
+///
+/// Functions that only inspect an image and answer a question about its pixel
+/// values. None of these modify the [`ImageBuffer`].
+impl ImageBuffer {
+    /// If all pixels of the image have the same values (within `threshold`
+    /// for each channel), returns `Some` with those values. Otherwise returns
+    /// `None`.
+    ///
+    /// The returned `Vec` has one entry per channel of the image.
+    pub fn is_constant_color(&self, threshold: f32) -> Option<Vec<f32>> {
+        self.is_constant_color_ffi(threshold, &Options::default())
+    }
+
+    /// If all pixels of the image within `options.region` have the same values
+    /// (within `threshold` for each channel), returns `Some` with those values.
+    /// Otherwise returns `None`.
+    ///
+    /// The returned `Vec` has one entry per channel of the image. Channels
+    /// outside of the channel range of `options.region` are set to `0.0`.
+    pub fn is_constant_color_with(&self, threshold: f32, options: &Options) -> Option<Vec<f32>> {
+        self.is_constant_color_ffi(threshold, options)
+    }
+
+    /// Returns `true` if all pixels of the image have the given `value` (within
+    /// `threshold`) in `channel`.
+    ///
+    /// This is handy for checking e.g. if an alpha channel is fully opaque.
+    pub fn is_constant_channel(&self, channel: u32, value: f32, threshold: f32) -> bool {
+        self.is_constant_channel_ffi(channel, value, threshold, &Options::default())
+    }
+
+    /// Returns `true` if all pixels of the image within `options.region` have
+    /// the given `value` (within `threshold`) in `channel`.
+    pub fn is_constant_channel_with(
+        &self,
+        channel: u32,
+        value: f32,
+        threshold: f32,
+        options: &Options,
+    ) -> bool {
+        self.is_constant_channel_ffi(channel, value, threshold, options)
+    }
+
+    /// Returns `true` if the image is monochrome.
+    ///
+    /// I.e. for every pixel all of the color channels have the same value
+    /// (within `threshold`). An image with less than two color channels is
+    /// always considered monochrome.
+    pub fn is_monochrome(&self, threshold: f32) -> bool {
+        self.is_monochrome_ffi(threshold, &Options::default())
+    }
+
+    /// Returns `true` if the image is monochrome within `options.region`.
+    ///
+    /// Only the channel range of `options.region` is considered. Use this to
+    /// e.g. exclude an alpha channel from the test.
+    pub fn is_monochrome_with(&self, threshold: f32, options: &Options) -> bool {
+        self.is_monochrome_ffi(threshold, options)
+    }
+
+    /// Returns the smallest [`Bounds`] containing all pixels that have at least
+    /// one non-zero channel value.
+    ///
+    /// If all pixels are zero the result [`is_empty()`](Bounds::is_empty).
+    pub fn nonzero_region(&self) -> Bounds {
+        self.nonzero_region_ffi(&Options::default())
+    }
+
+    /// Returns the smallest [`Bounds`] inside `options.region` containing all
+    /// pixels that have at least one non-zero value in the channels given by
+    /// `options.region`.
+    pub fn nonzero_region_with(&self, options: &Options) -> Bounds {
+        self.nonzero_region_ffi(options)
+    }
+}
+
+// Internal predicate FFI calls.
+impl ImageBuffer {
+    #[inline]
+    fn is_constant_color_ffi(&self, threshold: f32, options: &Options) -> Option<Vec<f32>> {
+        let mut color = vec![0.0f32; self.channel_count() as _];
+        let mut is_constant = MaybeUninit::<bool>::uninit();
+
+        unsafe {
+            oiio_ImageBufAlgo_isConstantColor(
+                self.as_raw_ptr(),
+                threshold,
+                color.as_mut_ptr(),
+                color.len() as _,
+                options.region.clone().into(),
+                options.thread_count as _,
+                &raw mut is_constant as _,
+            );
+
+            if is_constant.assume_init() {
+                Some(color)
+            } else {
+                None
+            }
+        }
+    }
+
+    #[inline]
+    fn is_constant_channel_ffi(
+        &self,
+        channel: u32,
+        value: f32,
+        threshold: f32,
+        options: &Options,
+    ) -> bool {
+        let mut is_constant = MaybeUninit::<bool>::uninit();
+
+        unsafe {
+            oiio_ImageBufAlgo_isConstantChannel(
+                self.as_raw_ptr(),
+                channel as _,
+                value,
+                threshold,
+                options.region.clone().into(),
+                options.thread_count as _,
+                &raw mut is_constant as _,
+            );
+
+            is_constant.assume_init()
+        }
+    }
+
+    #[inline]
+    fn is_monochrome_ffi(&self, threshold: f32, options: &Options) -> bool {
+        let mut is_monochrome = MaybeUninit::<bool>::uninit();
+
+        unsafe {
+            oiio_ImageBufAlgo_isMonochrome(
+                self.as_raw_ptr(),
+                threshold,
+                options.region.clone().into(),
+                options.thread_count as _,
+                &raw mut is_monochrome as _,
+            );
+
+            is_monochrome.assume_init()
+        }
+    }
+
+    #[inline]
+    fn nonzero_region_ffi(&self, options: &Options) -> Bounds {
+        let mut bounds = MaybeUninit::<oiio_ROI_t>::uninit();
+
+        unsafe {
+            oiio_ImageBufAlgo_nonzero_region(
+                self.as_raw_ptr(),
+                options.region.clone().into(),
+                options.thread_count as _,
+                &raw mut bounds as _,
+            );
+
+            bounds.assume_init().into()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{algorithms::*, *};
+
+    #[test]
+    fn predicates() -> Result<()> {
+        let gray = [0.5, 0.5, 0.5, 1.0];
+        let mut image_buffer =
+            ImageBuffer::from_fill(&gray, &Bounds::new(0..64, 0..64, 0..1, Some(0..4)))?;
+
+        assert_eq!(Some(gray.to_vec()), image_buffer.is_constant_color(0.0));
+        assert!(image_buffer.is_constant_channel(3, 1.0, 0.0));
+        assert!(image_buffer.is_monochrome_with(
+            0.0,
+            &Options {
+                region: Region::Bounds(Bounds::new(0..64, 0..64, 0..1, Some(0..3))),
+                ..Default::default()
+            }
+        ));
+
+        image_buffer.zero()?;
+        image_buffer.set_pixel(10, 20, None, &[1.0, 0.0, 0.0, 1.0]);
+
+        assert_eq!(None, image_buffer.is_constant_color(0.0));
+        assert!(!image_buffer.is_monochrome(0.0));
+        assert_eq!(
+            Bounds::new(10..11, 20..21, 0..1, Some(0..4)),
+            image_buffer.nonzero_region()
+        );
+
+        Ok(())
+    }
+}
@@ -1,4 +1,4 @@
-use crate::*;
+use crate::{algorithms::*, *};
 
 /// # Re-Orient
 ///
@@ -45,6 +45,343 @@ impl ImageBuffer {
     }
 }
 
+/// # Rotate 90°
+///
+/// Rotate the image 90° clockwise.
+///
+/// This is lossless. The data window is rotated about the center of the
+/// display window, which is rotated as well.
+impl ImageBuffer {
+    #[named]
+    pub fn replace_by_rotate90(&mut self, source: &ImageBuffer) -> Result<&mut Self> {
+        let is_ok = self.source_region_ffi(oiio_ImageBufAlgo_rotate90, source, &Options::default());
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+
+    #[named]
+    pub fn replace_by_rotate90_with(
+        &mut self,
+        source: &ImageBuffer,
+        options: &Options,
+    ) -> Result<&mut Self> {
+        let is_ok = self.source_region_ffi(oiio_ImageBufAlgo_rotate90, source, options);
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+
+    #[named]
+    pub fn rotate90(&mut self) -> Result<&mut Self> {
+        let mut image_buffer = ImageBuffer::new();
+        let is_ok =
+            image_buffer.source_region_ffi(oiio_ImageBufAlgo_rotate90, self, &Options::default());
+        *self = image_buffer;
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+
+    #[named]
+    pub fn rotate90_with(&mut self, options: &Options) -> Result<&mut Self> {
+        let mut image_buffer = ImageBuffer::new();
+        let is_ok = image_buffer.source_region_ffi(oiio_ImageBufAlgo_rotate90, self, options);
+        *self = image_buffer;
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+}
+
+/// # Rotate 180°
+///
+/// Rotate the image 180°.
+///
+/// This is lossless. The data window is rotated about the center of the
+/// display window.
+impl ImageBuffer {
+    #[named]
+    pub fn replace_by_rotate180(&mut self, source: &ImageBuffer) -> Result<&mut Self> {
+        let is_ok =
+            self.source_region_ffi(oiio_ImageBufAlgo_rotate180, source, &Options::default());
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+
+    #[named]
+    pub fn replace_by_rotate180_with(
+        &mut self,
+        source: &ImageBuffer,
+        options: &Options,
+    ) -> Result<&mut Self> {
+        let is_ok = self.source_region_ffi(oiio_ImageBufAlgo_rotate180, source, options);
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+
+    #[named]
+    pub fn rotate180(&mut self) -> Result<&mut Self> {
+        let mut image_buffer = ImageBuffer::new();
+        let is_ok =
+            image_buffer.source_region_ffi(oiio_ImageBufAlgo_rotate180, self, &Options::default());
+        *self = image_buffer;
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+
+    #[named]
+    pub fn rotate180_with(&mut self, options: &Options) -> Result<&mut Self> {
+        let mut image_buffer = ImageBuffer::new();
+        let is_ok = image_buffer.source_region_ffi(oiio_ImageBufAlgo_rotate180, self, options);
+        *self = image_buffer;
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+}
+
+/// # Rotate 270°
+///
+/// Rotate the image 270° clockwise (i.e. 90° counter-clockwise).
+///
+/// This is lossless. The data window is rotated about the center of the
+/// display window, which is rotated as well.
+impl ImageBuffer {
+    #[named]
+    pub fn replace_by_rotate270(&mut self, source: &ImageBuffer) -> Result<&mut Self> {
+        let is_ok =
+            self.source_region_ffi(oiio_ImageBufAlgo_rotate270, source, &Options::default());
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+
+    #[named]
+    pub fn replace_by_rotate270_with(
+        &mut self,
+        source: &ImageBuffer,
+        options: &Options,
+    ) -> Result<&mut Self> {
+        let is_ok = self.source_region_ffi(oiio_ImageBufAlgo_rotate270, source, options);
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+
+    #[named]
+    pub fn rotate270(&mut self) -> Result<&mut Self> {
+        let mut image_buffer = ImageBuffer::new();
+        let is_ok =
+            image_buffer.source_region_ffi(oiio_ImageBufAlgo_rotate270, self, &Options::default());
+        *self = image_buffer;
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+
+    #[named]
+    pub fn rotate270_with(&mut self, options: &Options) -> Result<&mut Self> {
+        let mut image_buffer = ImageBuffer::new();
+        let is_ok = image_buffer.source_region_ffi(oiio_ImageBufAlgo_rotate270, self, options);
+        *self = image_buffer;
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+}
+
+/// # Flip
+///
+/// Mirror the image vertically, i.e. top to bottom.
+///
+/// The data window is mirrored about the center of the display window.
+impl ImageBuffer {
+    #[named]
+    pub fn replace_by_flip(&mut self, source: &ImageBuffer) -> Result<&mut Self> {
+        let is_ok = self.source_region_ffi(oiio_ImageBufAlgo_flip, source, &Options::default());
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+
+    #[named]
+    pub fn replace_by_flip_with(
+        &mut self,
+        source: &ImageBuffer,
+        options: &Options,
+    ) -> Result<&mut Self> {
+        let is_ok = self.source_region_ffi(oiio_ImageBufAlgo_flip, source, options);
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+
+    #[named]
+    pub fn flip(&mut self) -> Result<&mut Self> {
+        let mut image_buffer = ImageBuffer::new();
+        let is_ok =
+            image_buffer.source_region_ffi(oiio_ImageBufAlgo_flip, self, &Options::default());
+        *self = image_buffer;
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+
+    #[named]
+    pub fn flip_with(&mut self, options: &Options) -> Result<&mut Self> {
+        let mut image_buffer = ImageBuffer::new();
+        let is_ok = image_buffer.source_region_ffi(oiio_ImageBufAlgo_flip, self, options);
+        *self = image_buffer;
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+}
+
+/// # Flop
+///
+/// Mirror the image horizontally, i.e. left to right.
+///
+/// The data window is mirrored about the center of the display window.
+impl ImageBuffer {
+    #[named]
+    pub fn replace_by_flop(&mut self, source: &ImageBuffer) -> Result<&mut Self> {
+        let is_ok = self.source_region_ffi(oiio_ImageBufAlgo_flop, source, &Options::default());
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+
+    #[named]
+    pub fn replace_by_flop_with(
+        &mut self,
+        source: &ImageBuffer,
+        options: &Options,
+    ) -> Result<&mut Self> {
+        let is_ok = self.source_region_ffi(oiio_ImageBufAlgo_flop, source, options);
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+
+    #[named]
+    pub fn flop(&mut self) -> Result<&mut Self> {
+        let mut image_buffer = ImageBuffer::new();
+        let is_ok =
+            image_buffer.source_region_ffi(oiio_ImageBufAlgo_flop, self, &Options::default());
+        *self = image_buffer;
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+
+    #[named]
+    pub fn flop_with(&mut self, options: &Options) -> Result<&mut Self> {
+        let mut image_buffer = ImageBuffer::new();
+        let is_ok = image_buffer.source_region_ffi(oiio_ImageBufAlgo_flop, self, options);
+        *self = image_buffer;
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+}
+
+/// # Transpose
+///
+/// Swap the x and y axes of the image, i.e. mirror it about the diagonal
+/// running from the top left to the bottom right.
+impl ImageBuffer {
+    #[named]
+    pub fn replace_by_transpose(&mut self, source: &ImageBuffer) -> Result<&mut Self> {
+        let is_ok =
+            self.source_region_ffi(oiio_ImageBufAlgo_transpose, source, &Options::default());
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+
+    #[named]
+    pub fn replace_by_transpose_with(
+        &mut self,
+        source: &ImageBuffer,
+        options: &Options,
+    ) -> Result<&mut Self> {
+        let is_ok = self.source_region_ffi(oiio_ImageBufAlgo_transpose, source, options);
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+
+    #[named]
+    pub fn transpose(&mut self) -> Result<&mut Self> {
+        let mut image_buffer = ImageBuffer::new();
+        let is_ok =
+            image_buffer.source_region_ffi(oiio_ImageBufAlgo_transpose, self, &Options::default());
+        *self = image_buffer;
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+
+    #[named]
+    pub fn transpose_with(&mut self, options: &Options) -> Result<&mut Self> {
+        let mut image_buffer = ImageBuffer::new();
+        let is_ok = image_buffer.source_region_ffi(oiio_ImageBufAlgo_transpose, self, options);
+        *self = image_buffer;
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+}
+
+/// # Circular Shift
+///
+/// Shift the pixels of the image by the given offsets, wrapping pixels that
+/// fall off one edge back around to the opposite edge.
+///
+/// The wrapping happens within the bounds of `options.region` (or the data
+/// window, if that is [`Region::All`]).
+///
+/// If `z_shift` is `None` it is assumed to be zero.
+impl ImageBuffer {
+    #[named]
+    pub fn replace_by_circular_shift(
+        &mut self,
+        source: &ImageBuffer,
+        x_shift: i32,
+        y_shift: i32,
+        z_shift: Option<i32>,
+    ) -> Result<&mut Self> {
+        let is_ok = self.circular_shift_ffi(source, x_shift, y_shift, z_shift, &Options::default());
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+
+    #[named]
+    pub fn replace_by_circular_shift_with(
+        &mut self,
+        source: &ImageBuffer,
+        x_shift: i32,
+        y_shift: i32,
+        z_shift: Option<i32>,
+        options: &Options,
+    ) -> Result<&mut Self> {
+        let is_ok = self.circular_shift_ffi(source, x_shift, y_shift, z_shift, options);
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+
+    #[named]
+    pub fn circular_shift(
+        &mut self,
+        x_shift: i32,
+        y_shift: i32,
+        z_shift: Option<i32>,
+    ) -> Result<&mut Self> {
+        let mut image_buffer = ImageBuffer::new();
+        let is_ok =
+            image_buffer.circular_shift_ffi(self, x_shift, y_shift, z_shift, &Options::default());
+        *self = image_buffer;
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+
+    #[named]
+    pub fn circular_shift_with(
+        &mut self,
+        x_shift: i32,
+        y_shift: i32,
+        z_shift: Option<i32>,
+        options: &Options,
+    ) -> Result<&mut Self> {
+        let mut image_buffer = ImageBuffer::new();
+        let is_ok = image_buffer.circular_shift_ffi(self, x_shift, y_shift, z_shift, options);
+        *self = image_buffer;
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+}
+
 impl ImageBuffer {
     #[inline]
     fn re_orient_ffi(&mut self, source: &ImageBuffer, thread_count: u16) -> bool {
@@ -61,6 +398,33 @@ impl ImageBuffer {
             is_ok.assume_init()
         }
     }
+
+    #[inline]
+    fn circular_shift_ffi(
+        &mut self,
+        source: &ImageBuffer,
+        x_shift: i32,
+        y_shift: i32,
+        z_shift: Option<i32>,
+        options: &Options,
+    ) -> bool {
+        let mut is_ok = MaybeUninit::<bool>::uninit();
+
+        unsafe {
+            oiio_ImageBufAlgo_circular_shift(
+                self.as_raw_ptr_mut(),
+                source.as_raw_ptr(),
+                x_shift,
+                y_shift,
+                z_shift.unwrap_or(0),
+                options.region.clone().into(),
+                options.thread_count as _,
+                &raw mut is_ok as _,
+            );
+
+            is_ok.assume_init()
+        }
+    }
 }
 
 #[cfg(test)]
@@ -72,8 +436,48 @@ mod tests {
         let mut image_buffer =
             ImageBuffer::from_file(Utf8Path::new("assets/j0.3toD__F16_RGBA.exr"))?;
 
+        image_buffer.set_orientation(Orientation::Rotated90Clockwise);
+        assert_eq!(Orientation::Rotated90Clockwise, image_buffer.orientation());
+
         image_buffer.re_orient()?;
+        assert_eq!(Orientation::Normal, image_buffer.orientation());
 
         Ok(())
     }
+
+    #[test]
+    fn orthogonal() -> Result<()> {
+        let original = ImageBuffer::from_file(Utf8Path::new("assets/j0.3toD__F16_RGBA.exr"))?;
+
+        let mut image_buffer = original.clone();
+        image_buffer.rotate90()?.rotate270()?;
+        assert_eq!(0, original.compare(&image_buffer, 0.0, 0.0).failure_count);
+
+        image_buffer.flip()?.flop()?.rotate180()?;
+        assert_eq!(0, original.compare(&image_buffer, 0.0, 0.0).failure_count);
+
+        image_buffer.transpose()?.transpose()?;
+        assert_eq!(0, original.compare(&image_buffer, 0.0, 0.0).failure_count);
+
+        image_buffer.circular_shift(100, 50, None)?;
+
+        // Pixels move by the shift and wrap around at the edges.
+        let data_window = original.data_window();
+        let (width, height) = (data_window.width() as i32, data_window.height() as i32);
+        let (x_start, y_start) = (data_window.x().start, data_window.y().start);
+
+        for (x, y) in [(7, 3), (width - 1, height - 1)] {
+            assert_eq!(
+                original.get_pixel(x_start + x, y_start + y, None, WrapMode::Black),
+                image_buffer.get_pixel(
+                    x_start + (x + 100) % width,
+                    y_start + (y + 50) % height,
+                    None,
+                    WrapMode::Black
+                )
+            );
+        }
+
+        image_buffer.write(Utf8Path::new("target/circular_shift.exr"))
+    }
 }
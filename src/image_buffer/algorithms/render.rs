@@ -0,0 +1,533 @@
+use crate::*;
+
+/// The number of samples per pixel along each axis that are used to compute
+/// the coverage of anti-aliased shapes.
+const SHAPE_SAMPLE_COUNT: u32 = 4;
+
+/// Optional parameters for [`ImageBuffer`]'s
+/// [`render_point_with()`](ImageBuffer::render_point_with) method.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RenderPointOptions<'a> {
+    /// Color for drawing the point, defaulting to opaque white `[1.0, 1.0, …]`
+    /// in all channels.
+    ///
+    /// If the image has an alpha channel, the color is composited *over* the
+    /// existing pixels using the alpha value of the color.
+    pub color: &'a [f32],
+    /// See the [Region](#region-of-interest) section on [`ImageBuffer`].
+    pub region: Region,
+    /// See the [Multithreading](#multithreading) section on [`ImageBuffer`].
+    pub thread_count: u16,
+}
+
+impl Default for RenderPointOptions<'_> {
+    fn default() -> Self {
+        Self {
+            color: &[1.0],
+            region: Region::default(),
+            thread_count: 0,
+        }
+    }
+}
+
+/// Optional parameters for [`ImageBuffer`]'s
+/// [`render_line_with()`](ImageBuffer::render_line_with) method.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RenderLineOptions<'a> {
+    /// Color for drawing the line, defaulting to opaque white `[1.0, 1.0, …]`
+    /// in all channels.
+    ///
+    /// If the image has an alpha channel, the color is composited *over* the
+    /// existing pixels using the alpha value of the color.
+    pub color: &'a [f32],
+    /// If `true`, the first point of the line is not drawn.
+    ///
+    /// This is useful to draw connected line segments (a polyline) with a
+    /// semi-transparent color without compositing the shared points twice.
+    pub skip_first_point: bool,
+    /// See the [Region](#region-of-interest) section on [`ImageBuffer`].
+    pub region: Region,
+    /// See the [Multithreading](#multithreading) section on [`ImageBuffer`].
+    pub thread_count: u16,
+}
+
+impl Default for RenderLineOptions<'_> {
+    fn default() -> Self {
+        Self {
+            color: &[1.0],
+            skip_first_point: false,
+            region: Region::default(),
+            thread_count: 0,
+        }
+    }
+}
+
+/// Optional parameters for [`ImageBuffer`]'s
+/// [`render_box_with()`](ImageBuffer::render_box_with) method.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RenderBoxOptions<'a> {
+    /// Color for drawing the box, defaulting to opaque white `[1.0, 1.0, …]`
+    /// in all channels.
+    ///
+    /// If the image has an alpha channel, the color is composited *over* the
+    /// existing pixels using the alpha value of the color.
+    pub color: &'a [f32],
+    /// If `true`, the box is filled. Otherwise only its outline is drawn.
+    pub fill: bool,
+    /// See the [Region](#region-of-interest) section on [`ImageBuffer`].
+    pub region: Region,
+    /// See the [Multithreading](#multithreading) section on [`ImageBuffer`].
+    pub thread_count: u16,
+}
+
+impl Default for RenderBoxOptions<'_> {
+    fn default() -> Self {
+        Self {
+            color: &[1.0],
+            fill: false,
+            region: Region::default(),
+            thread_count: 0,
+        }
+    }
+}
+
+/// Optional parameters for [`ImageBuffer`]'s
+/// [`render_polygon_with()`](ImageBuffer::render_polygon_with) and
+/// [`render_circle_with()`](ImageBuffer::render_circle_with) methods.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RenderShapeOptions<'a> {
+    /// Color for drawing the shape, defaulting to opaque white
+    /// `[1.0, 1.0, …]` in all channels.
+    ///
+    /// The color is composited *over* the existing pixels using the alpha
+    /// value of the color (if the image has an alpha channel) multiplied by
+    /// the coverage of each pixel.
+    pub color: &'a [f32],
+    /// See the [Region](#region-of-interest) section on [`ImageBuffer`].
+    pub region: Region,
+}
+
+impl Default for RenderShapeOptions<'_> {
+    fn default() -> Self {
+        Self {
+            color: &[1.0],
+            region: Region::default(),
+        }
+    }
+}
+
+/// # Render Point
+///
+/// Draw a single point at pixel (`x`, `y`).
+impl ImageBuffer {
+    #[named]
+    pub fn render_point(&mut self, x: i32, y: i32) -> Result<&mut Self> {
+        let is_ok = self.render_point_ffi(x, y, &RenderPointOptions::default());
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+
+    #[named]
+    pub fn render_point_with(
+        &mut self,
+        x: i32,
+        y: i32,
+        options: &RenderPointOptions,
+    ) -> Result<&mut Self> {
+        let is_ok = self.render_point_ffi(x, y, options);
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+}
+
+/// # Render Line
+///
+/// Draw a line from pixel (`x1`, `y1`) to pixel (`x2`, `y2`).
+///
+/// The line is one pixel wide and not anti-aliased. Both end points are drawn
+/// unless [`skip_first_point`](RenderLineOptions::skip_first_point) is set.
+impl ImageBuffer {
+    #[named]
+    pub fn render_line(&mut self, x1: i32, y1: i32, x2: i32, y2: i32) -> Result<&mut Self> {
+        let is_ok = self.render_line_ffi(x1, y1, x2, y2, &RenderLineOptions::default());
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+
+    #[named]
+    pub fn render_line_with(
+        &mut self,
+        x1: i32,
+        y1: i32,
+        x2: i32,
+        y2: i32,
+        options: &RenderLineOptions,
+    ) -> Result<&mut Self> {
+        let is_ok = self.render_line_ffi(x1, y1, x2, y2, options);
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+}
+
+/// # Render Box
+///
+/// Draw a box with the corners (`x1`, `y1`) and (`x2`, `y2`).
+///
+/// Both corners are inclusive, i.e. the box covers
+/// `x2 - x1 + 1` × `y2 - y1 + 1` pixels. Set
+/// [`fill`](RenderBoxOptions::fill) to draw a filled box instead of its
+/// outline.
+impl ImageBuffer {
+    #[named]
+    pub fn render_box(&mut self, x1: i32, y1: i32, x2: i32, y2: i32) -> Result<&mut Self> {
+        let is_ok = self.render_box_ffi(x1, y1, x2, y2, &RenderBoxOptions::default());
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+
+    #[named]
+    pub fn render_box_with(
+        &mut self,
+        x1: i32,
+        y1: i32,
+        x2: i32,
+        y2: i32,
+        options: &RenderBoxOptions,
+    ) -> Result<&mut Self> {
+        let is_ok = self.render_box_ffi(x1, y1, x2, y2, options);
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+}
+
+/// # Render Polygon
+///
+/// Draw a filled, anti-aliased polygon.
+///
+/// The `points` are in continuous pixel coordinates, i.e. the center of pixel
+/// (`x`, `y`) is at (`x + 0.5`, `y + 0.5`). The polygon is closed
+/// automatically. Self-intersecting polygons are filled using the *non-zero*
+/// winding rule.
+///
+/// Use [`render_line()`](ImageBuffer::render_line) to draw outlines.
+impl ImageBuffer {
+    pub fn render_polygon(&mut self, points: &[(f32, f32)]) -> Result<&mut Self> {
+        self.render_polygon_with(points, &RenderShapeOptions::default())
+    }
+
+    pub fn render_polygon_with(
+        &mut self,
+        points: &[(f32, f32)],
+        options: &RenderShapeOptions,
+    ) -> Result<&mut Self> {
+        if points.len() < 3 {
+            return Ok(self);
+        }
+
+        let (x_min, y_min, x_max, y_max) = points.iter().fold(
+            (f32::MAX, f32::MAX, f32::MIN, f32::MIN),
+            |(x_min, y_min, x_max, y_max), &(x, y)| {
+                (x_min.min(x), y_min.min(y), x_max.max(x), y_max.max(y))
+            },
+        );
+
+        self.render_shape_impl(
+            (x_min, y_min, x_max, y_max),
+            |x, y| 0 != winding_number(points, x, y),
+            options,
+        )?;
+
+        Ok(self)
+    }
+}
+
+/// # Render Circle
+///
+/// Draw a filled, anti-aliased circle.
+///
+/// The center is in continuous pixel coordinates, i.e. the center of pixel
+/// (`x`, `y`) is at (`x + 0.5`, `y + 0.5`).
+impl ImageBuffer {
+    pub fn render_circle(&mut self, x: f32, y: f32, radius: f32) -> Result<&mut Self> {
+        self.render_circle_with(x, y, radius, &RenderShapeOptions::default())
+    }
+
+    pub fn render_circle_with(
+        &mut self,
+        x: f32,
+        y: f32,
+        radius: f32,
+        options: &RenderShapeOptions,
+    ) -> Result<&mut Self> {
+        let radius_squared = radius * radius;
+
+        self.render_shape_impl(
+            (x - radius, y - radius, x + radius, y + radius),
+            |sample_x, sample_y| (sample_x - x).powi(2) + (sample_y - y).powi(2) <= radius_squared,
+            options,
+        )?;
+
+        Ok(self)
+    }
+}
+
+impl ImageBuffer {
+    #[inline]
+    fn render_point_ffi(&mut self, x: i32, y: i32, options: &RenderPointOptions) -> bool {
+        let mut is_ok = MaybeUninit::<bool>::uninit();
+
+        unsafe {
+            oiio_ImageBufAlgo_render_point(
+                self.as_raw_ptr_mut(),
+                x as _,
+                y as _,
+                CspanF32::new(options.color).as_raw_ptr() as _,
+                options.region.clone().into(),
+                options.thread_count as _,
+                &raw mut is_ok as _,
+            );
+
+            is_ok.assume_init()
+        }
+    }
+
+    #[inline]
+    fn render_line_ffi(
+        &mut self,
+        x1: i32,
+        y1: i32,
+        x2: i32,
+        y2: i32,
+        options: &RenderLineOptions,
+    ) -> bool {
+        let mut is_ok = MaybeUninit::<bool>::uninit();
+
+        unsafe {
+            oiio_ImageBufAlgo_render_line(
+                self.as_raw_ptr_mut(),
+                x1 as _,
+                y1 as _,
+                x2 as _,
+                y2 as _,
+                CspanF32::new(options.color).as_raw_ptr() as _,
+                options.skip_first_point,
+                options.region.clone().into(),
+                options.thread_count as _,
+                &raw mut is_ok as _,
+            );
+
+            is_ok.assume_init()
+        }
+    }
+
+    #[inline]
+    fn render_box_ffi(
+        &mut self,
+        x1: i32,
+        y1: i32,
+        x2: i32,
+        y2: i32,
+        options: &RenderBoxOptions,
+    ) -> bool {
+        let mut is_ok = MaybeUninit::<bool>::uninit();
+
+        unsafe {
+            oiio_ImageBufAlgo_render_box(
+                self.as_raw_ptr_mut(),
+                x1 as _,
+                y1 as _,
+                x2 as _,
+                y2 as _,
+                CspanF32::new(options.color).as_raw_ptr() as _,
+                options.fill,
+                options.region.clone().into(),
+                options.thread_count as _,
+                &raw mut is_ok as _,
+            );
+
+            is_ok.assume_init()
+        }
+    }
+
+    /// Composites `options.color` over all pixels inside the shape's bounding
+    /// box (`x_min`, `y_min`, `x_max`, `y_max`), weighted by the fraction of
+    /// samples for which `is_inside` returns `true`.
+    fn render_shape_impl(
+        &mut self,
+        (x_min, y_min, x_max, y_max): (f32, f32, f32, f32),
+        is_inside: impl Fn(f32, f32) -> bool,
+        options: &RenderShapeOptions,
+    ) -> Result<()> {
+        let mut bounds = match &options.region {
+            Region::All => {
+                let mut bounds = self.data_window();
+                bounds.set_channel(0..self.channel_count() as _);
+                bounds
+            }
+            Region::Bounds(bounds) => bounds.clone(),
+        };
+
+        let x_start = (x_min.floor() as i32).max(bounds.x_start());
+        let x_end = (x_max.ceil() as i32).min(bounds.x_end());
+        let y_start = (y_min.floor() as i32).max(bounds.y_start());
+        let y_end = (y_max.ceil() as i32).min(bounds.y_end());
+
+        // The shape is entirely outside of the image.
+        if x_end <= x_start || y_end <= y_start {
+            return Ok(());
+        }
+
+        bounds.set_x(x_start..x_end);
+        bounds.set_y(y_start..y_end);
+
+        let region = Region::Bounds(bounds.clone());
+        let mut pixels: Vec<f32> = self.pixels(&region)?;
+
+        let channel = bounds.channel().clone();
+        let alpha_channel_index = self.image_spec().alpha_channel_index;
+
+        // The last value is replicated for any missing channel.
+        let color = |channel: u32| {
+            options
+                .color
+                .get(channel as usize)
+                .or(options.color.last())
+                .copied()
+                .unwrap_or(1.0)
+        };
+        let color_alpha = alpha_channel_index.map_or(1.0, color);
+
+        let width = bounds.width() as usize;
+        let height = bounds.height() as usize;
+        let sample_step = 1.0 / SHAPE_SAMPLE_COUNT as f32;
+
+        pixels
+            .chunks_exact_mut(channel.len())
+            .enumerate()
+            .for_each(|(index, pixel)| {
+                let x = (x_start + (index % width) as i32) as f32;
+                let y = (y_start + (index / width % height) as i32) as f32;
+
+                let covered_sample_count = (0..SHAPE_SAMPLE_COUNT)
+                    .flat_map(|j| (0..SHAPE_SAMPLE_COUNT).map(move |i| (i, j)))
+                    .filter(|&(i, j)| {
+                        is_inside(
+                            x + (i as f32 + 0.5) * sample_step,
+                            y + (j as f32 + 0.5) * sample_step,
+                        )
+                    })
+                    .count();
+
+                if 0 == covered_sample_count {
+                    return;
+                }
+
+                let opacity = color_alpha * covered_sample_count as f32
+                    / (SHAPE_SAMPLE_COUNT * SHAPE_SAMPLE_COUNT) as f32;
+
+                pixel
+                    .iter_mut()
+                    .zip(channel.clone())
+                    .for_each(|(value, channel)| {
+                        let source = if Some(channel) == alpha_channel_index {
+                            1.0
+                        } else {
+                            color(channel)
+                        };
+
+                        *value = source * opacity + *value * (1.0 - opacity);
+                    });
+            });
+
+        self.set_pixels(&pixels, &region)
+    }
+}
+
+/// Returns the winding number of the closed polygon `points` around
+/// (`x`, `y`).
+fn winding_number(points: &[(f32, f32)], x: f32, y: f32) -> i32 {
+    points.iter().zip(points.iter().cycle().skip(1)).fold(
+        0,
+        |winding_number, (&(x0, y0), &(x1, y1))| {
+            // Which side of the edge the point is on.
+            let side = (x1 - x0) * (y - y0) - (x - x0) * (y1 - y0);
+
+            if y0 <= y {
+                if y < y1 && 0.0 < side {
+                    return winding_number + 1;
+                }
+            } else if y1 <= y && side < 0.0 {
+                return winding_number - 1;
+            }
+
+            winding_number
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{algorithms::*, *};
+
+    #[test]
+    fn render() -> Result<()> {
+        let mut image_buffer =
+            ImageBuffer::from_fill(&[0.0, 0.0, 0.0, 1.0], &Bounds::new_2d(0..128, 0..128))?;
+
+        image_buffer
+            .render_box_with(
+                8,
+                8,
+                23,
+                23,
+                &RenderBoxOptions {
+                    color: &[1.0, 0.0, 0.0, 1.0],
+                    fill: true,
+                    ..Default::default()
+                },
+            )?
+            .render_line(0, 127, 127, 0)?
+            .render_point(64, 100)?
+            .render_polygon_with(
+                &[(32.0, 96.0), (64.0, 120.0), (16.0, 120.0)],
+                &RenderShapeOptions {
+                    color: &[0.0, 1.0, 0.0, 1.0],
+                    ..Default::default()
+                },
+            )?
+            .render_circle_with(
+                96.0,
+                96.0,
+                16.0,
+                &RenderShapeOptions {
+                    color: &[0.0, 0.0, 1.0, 0.5],
+                    ..Default::default()
+                },
+            )?;
+
+        assert_eq!(
+            Some(vec![1.0, 0.0, 0.0, 1.0]),
+            image_buffer.is_constant_color_with(
+                0.0,
+                &Options {
+                    region: Region::Bounds(Bounds::new_2d(8..24, 8..24)),
+                    ..Default::default()
+                }
+            )
+        );
+
+        // The center of the circle is fully covered.
+        assert_eq!(
+            Some(vec![0.0, 0.0, 0.5, 1.0]),
+            image_buffer.is_constant_color_with(
+                0.0,
+                &Options {
+                    region: Region::Bounds(Bounds::new_2d(94..98, 94..98)),
+                    ..Default::default()
+                }
+            )
+        );
+
+        image_buffer.write(Utf8Path::new("target/render.exr"))
+    }
+}
@@ -1,4 +1,4 @@
-use crate::*;
+use crate::{algorithms::*, *};
 
 /// # Render Text
 ///
@@ -172,6 +172,330 @@ impl Default for RenderTextOptions<'_> {
     }
 }
 
+/// Returns the bounds of `text` as it would be rendered by
+/// [`render_text()`](ImageBuffer::render_text) at the origin with the given
+/// font.
+///
+/// The bounds are relative to the position passed to `render_text()`. I.e.
+/// `y_start()` is usually negative as the text extends upwards from the
+/// baseline.
+///
+/// # Errors
+///
+/// Returns an error if the font can not be found or the text is empty.
+#[named]
+pub fn text_size(text: &str, font_size: u16, font_name: Option<&str>) -> Result<Bounds> {
+    let mut bounds = MaybeUninit::<oiio_ROI_t>::uninit();
+
+    let bounds: Region = unsafe {
+        oiio_ImageBufAlgo_text_size(
+            StringView::from(text).as_raw_ptr() as _,
+            font_size as _,
+            font_name
+                .map_or(StringView::default(), StringView::from)
+                .as_raw_ptr() as _,
+            &raw mut bounds as _,
+        );
+
+        bounds.assume_init().into()
+    };
+
+    match bounds {
+        Region::Bounds(bounds) => Ok(bounds),
+        Region::All => Err(anyhow!(
+            "[fn {}] Could not measure text '{}'",
+            function_name!(),
+            text
+        )),
+    }
+}
+
+/// A drop shadow rendered behind the text of a [`TextLayout`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct TextShadow<'a> {
+    /// The offset of the shadow from the text in pixels.
+    pub offset: (i32, i32),
+    /// Color of the shadow, defaulting to opaque black.
+    pub color: &'a [f32],
+}
+
+impl Default for TextShadow<'_> {
+    fn default() -> Self {
+        Self {
+            offset: (2, 2),
+            color: &[0.0, 0.0, 0.0, 1.0],
+        }
+    }
+}
+
+/// A filled box rendered behind the text of a [`TextLayout`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct TextBackground<'a> {
+    /// The space between the text (and its shadow) and the edges of the box in
+    /// pixels.
+    pub padding: u32,
+    /// Color of the box, defaulting to half transparent black.
+    pub color: &'a [f32],
+}
+
+impl Default for TextBackground<'_> {
+    fn default() -> Self {
+        Self {
+            padding: 4,
+            color: &[0.0, 0.0, 0.0, 0.5],
+        }
+    }
+}
+
+/// Parameters for [`TextLayout::new()`] and [`ImageBuffer`]'s
+/// [`render_text_layout_with()`](ImageBuffer::render_text_layout_with) method.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TextLayoutOptions<'a> {
+    /// The nominal height of the font (in pixels).
+    pub font_size: u16,
+    /// The name of the font. See [`RenderTextOptions::font_name`].
+    pub font_name: Option<&'a str>,
+    /// Color for drawing the text, defaulting to opaque white.
+    pub color: &'a [f32],
+    /// Alignment of the lines relative to the `x` position of the layout.
+    pub text_align_x: TextAlignX,
+    /// The distance between the baselines of two lines as a multiple of
+    /// [`font_size`](TextLayoutOptions::font_size).
+    pub line_spacing: f32,
+    /// If set, lines are broken between words so they are no wider than this
+    /// many pixels. A single word that is wider is put on a line of its own.
+    pub wrap_width: Option<u32>,
+    /// An optional drop shadow.
+    pub shadow: Option<TextShadow<'a>>,
+    /// An optional background plate.
+    pub background: Option<TextBackground<'a>>,
+    /// See the [Region](#region-of-interest) section on [`ImageBuffer`].
+    pub region: Region,
+    /// See the [Multithreading](#multithreading) section on [`ImageBuffer`].
+    pub thread_count: u16,
+}
+
+impl Default for TextLayoutOptions<'_> {
+    fn default() -> Self {
+        Self {
+            font_size: 16,
+            font_name: None,
+            color: &[1.0],
+            text_align_x: TextAlignX::default(),
+            line_spacing: 1.2,
+            wrap_width: None,
+            shadow: None,
+            background: None,
+            region: Region::default(),
+            thread_count: 0,
+        }
+    }
+}
+
+/// A single line of a [`TextLayout`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct TextLine {
+    /// The text of the line.
+    pub text: String,
+    /// The horizontal position of the line relative to the layout's origin.
+    pub x: i32,
+    /// The position of the line's baseline relative to the layout's origin.
+    pub y: i32,
+}
+
+/// Multiple lines of text, broken and positioned for rendering.
+///
+/// Use this to measure text before rendering it, e.g. to right-align a
+/// burn-in with the edge of an image. The first baseline is at the layout's
+/// origin.
+///
+/// Linefeed (`\n`) characters always start a new line. If
+/// [`wrap_width`](TextLayoutOptions::wrap_width) is set, lines are also broken
+/// between words.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TextLayout {
+    /// The lines of the layout.
+    pub lines: Vec<TextLine>,
+    /// The bounds of the rendered text, relative to the layout's origin.
+    ///
+    /// This does not include the shadow or the background.
+    pub bounds: Bounds,
+}
+
+impl TextLayout {
+    #[named]
+    pub fn new(text: &str, options: &TextLayoutOptions) -> Result<Self> {
+        let measure = |text: &str| text_size(text, options.font_size, options.font_name);
+
+        let mut lines = Vec::new();
+
+        for paragraph in text.split('\n') {
+            let Some(wrap_width) = options.wrap_width else {
+                lines.push(paragraph.to_string());
+                continue;
+            };
+
+            let mut line = String::new();
+            for word in paragraph.split_whitespace() {
+                let candidate = if line.is_empty() {
+                    word.to_string()
+                } else {
+                    format!("{line} {word}")
+                };
+
+                if !line.is_empty() && wrap_width < measure(&candidate)?.width() {
+                    lines.push(std::mem::replace(&mut line, word.to_string()));
+                } else {
+                    line = candidate;
+                }
+            }
+            lines.push(line);
+        }
+
+        let line_height = (options.font_size as f32 * options.line_spacing).round() as i32;
+
+        let mut bounds: Option<Bounds> = None;
+
+        let lines = lines
+            .into_iter()
+            .enumerate()
+            .map(|(index, text)| {
+                let y = index as i32 * line_height;
+
+                if text.trim().is_empty() {
+                    return Ok(TextLine { text, x: 0, y });
+                }
+
+                let line_bounds = measure(&text)?;
+
+                let x = match options.text_align_x {
+                    TextAlignX::Left => 0,
+                    TextAlignX::Center => -(line_bounds.x_start() + line_bounds.x_end()) / 2,
+                    TextAlignX::Right => -line_bounds.x_end(),
+                };
+
+                let line_bounds = translated(&line_bounds, x, y);
+                bounds = Some(match bounds.take() {
+                    Some(bounds) => Bounds::from_union(&bounds, &line_bounds),
+                    None => line_bounds,
+                });
+
+                Ok(TextLine { text, x, y })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self {
+            lines,
+            bounds: bounds.ok_or_else(|| {
+                anyhow!(
+                    "[fn {}] Text '{}' has no visible lines",
+                    function_name!(),
+                    text
+                )
+            })?,
+        })
+    }
+
+    /// The bounds covered by the rendered layout relative to its origin,
+    /// including the shadow and background of the given `options`.
+    pub fn rendered_bounds(&self, options: &TextLayoutOptions) -> Bounds {
+        let mut bounds = self.bounds.clone();
+
+        if let Some(shadow) = &options.shadow {
+            bounds.union(&translated(&self.bounds, shadow.offset.0, shadow.offset.1));
+        }
+
+        if let Some(background) = &options.background {
+            let padding = background.padding as i32;
+            bounds.set_x(bounds.x_start() - padding..bounds.x_end() + padding);
+            bounds.set_y(bounds.y_start() - padding..bounds.y_end() + padding);
+        }
+
+        bounds
+    }
+}
+
+/// Returns `bounds` moved by (`x`, `y`).
+fn translated(bounds: &Bounds, x: i32, y: i32) -> Bounds {
+    let mut bounds = bounds.clone();
+    bounds.set_x(bounds.x_start() + x..bounds.x_end() + x);
+    bounds.set_y(bounds.y_start() + y..bounds.y_end() + y);
+    bounds
+}
+
+/// # Render Text Layout
+///
+/// Render multiple lines of text, laid out by [`TextLayout`], with an
+/// optional drop shadow and background plate.
+///
+/// The first baseline is at (`x`, `y`). The background is rendered first,
+/// then the shadow and then the text, each composited *over* the existing
+/// pixels.
+impl ImageBuffer {
+    pub fn render_text_layout(&mut self, x: i32, y: i32, text: &str) -> Result<&mut Self> {
+        self.render_text_layout_with(x, y, text, &TextLayoutOptions::default())
+    }
+
+    pub fn render_text_layout_with(
+        &mut self,
+        x: i32,
+        y: i32,
+        text: &str,
+        options: &TextLayoutOptions,
+    ) -> Result<&mut Self> {
+        let layout = TextLayout::new(text, options)?;
+
+        if let Some(background) = &options.background {
+            let bounds = translated(&layout.rendered_bounds(options), x, y);
+
+            self.render_box_with(
+                bounds.x_start(),
+                bounds.y_start(),
+                bounds.x_end() - 1,
+                bounds.y_end() - 1,
+                &RenderBoxOptions {
+                    color: background.color,
+                    fill: true,
+                    region: options.region.clone(),
+                    thread_count: options.thread_count,
+                },
+            )?;
+        }
+
+        let mut render_lines = |offset: (i32, i32), color: &[f32]| -> Result<()> {
+            for line in layout
+                .lines
+                .iter()
+                .filter(|line| !line.text.trim().is_empty())
+            {
+                self.render_text_with(
+                    x + line.x + offset.0,
+                    y + line.y + offset.1,
+                    &line.text,
+                    &RenderTextOptions {
+                        font_size: options.font_size,
+                        font_name: options.font_name,
+                        color,
+                        region: options.region.clone(),
+                        thread_count: options.thread_count,
+                        ..Default::default()
+                    },
+                )?;
+            }
+
+            Ok(())
+        };
+
+        if let Some(shadow) = &options.shadow {
+            render_lines(shadow.offset, shadow.color)?;
+        }
+
+        render_lines((0, 0), options.color)?;
+
+        Ok(self)
+    }
+}
+
 impl ImageBuffer {
     #[inline]
     fn render_text_ffi(&mut self, x: i32, y: i32, text: &str, options: &RenderTextOptions) -> bool {
@@ -229,4 +553,36 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn text_layout() -> Result<()> {
+        let font_name = Some("assets/ProtestGuerrilla-Regular.ttf");
+
+        let bounds = text_size("Kringers Fossed!", 32, font_name)?;
+        assert!(0 < bounds.width());
+        assert!(bounds.y_start() < 0);
+
+        let options = TextLayoutOptions {
+            font_size: 32,
+            font_name,
+            text_align_x: TextAlignX::Right,
+            wrap_width: Some(bounds.width() - 1),
+            shadow: Some(TextShadow::default()),
+            background: Some(TextBackground::default()),
+            ..Default::default()
+        };
+
+        let layout = TextLayout::new("Kringers Fossed!\nfoo", &options)?;
+
+        // The first line is wrapped, the second one is not.
+        assert_eq!(3, layout.lines.len());
+        assert_eq!(0, layout.bounds.x_end());
+
+        let mut image_buffer =
+            ImageBuffer::from_fill(&[0.5, 0.5, 0.5, 1.0], &Bounds::new_2d(0..256, 0..256))?;
+
+        image_buffer.render_text_layout_with(240, 64, "Kringers Fossed!\nfoo", &options)?;
+
+        image_buffer.write(Utf8Path::new("target/render_text_layout.exr"))
+    }
 }
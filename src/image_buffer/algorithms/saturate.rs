@@ -0,0 +1,119 @@
+use crate::{algorithms::*, *};
+
+/// Optional parameters for [`ImageBuffer`]'s
+/// [`replace_by_saturate_with()`](ImageBuffer::replace_by_saturate_with) and
+/// [`saturate_with()`](ImageBuffer::saturate_with) methods.
+#[derive(Clone, Default)]
+pub struct SaturateOptions {
+    /// The index of the first of the three consecutive channels that are
+    /// treated as RGB.
+    pub first_channel: u32,
+    /// See the [Region of Interest](module@algorithms#region-of-interest)
+    /// section in the [module@algorithms] module. .
+    pub region: Region,
+    /// See the [Multithreading](module@algorithms#multithreading) section
+    /// in the [module@algorithms] module.
+    pub thread_count: u16,
+}
+
+/// # Saturate
+///
+/// Increase or decrease color saturation.
+///
+/// A `scale` of `0.0` removes all saturation (resulting in a grayscale image),
+/// `1.0` leaves the image unchanged and values > `1.0` increase saturation.
+///
+/// Only the three channels starting at
+/// [`first_channel`](SaturateOptions::first_channel) are altered. All other
+/// channels are copied unchanged.
+impl ImageBuffer {
+    #[named]
+    pub fn replace_by_saturate(&mut self, source: &ImageBuffer, scale: f32) -> Result<&mut Self> {
+        let is_ok = self.saturate_ffi(source, scale, &SaturateOptions::default());
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+
+    #[named]
+    pub fn replace_by_saturate_with(
+        &mut self,
+        source: &ImageBuffer,
+        scale: f32,
+        options: &SaturateOptions,
+    ) -> Result<&mut Self> {
+        let is_ok = self.saturate_ffi(source, scale, options);
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+
+    #[named]
+    pub fn saturate(&mut self, scale: f32) -> Result<&mut Self> {
+        let mut image_buffer = ImageBuffer::new();
+        let is_ok = image_buffer.saturate_ffi(self, scale, &SaturateOptions::default());
+        *self = image_buffer;
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+
+    #[named]
+    pub fn saturate_with(&mut self, scale: f32, options: &SaturateOptions) -> Result<&mut Self> {
+        let mut image_buffer = ImageBuffer::new();
+        let is_ok = image_buffer.saturate_ffi(self, scale, options);
+        *self = image_buffer;
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+}
+
+impl ImageBuffer {
+    #[inline]
+    fn saturate_ffi(
+        &mut self,
+        source: &ImageBuffer,
+        scale: f32,
+        options: &SaturateOptions,
+    ) -> bool {
+        let mut is_ok = MaybeUninit::<bool>::uninit();
+
+        unsafe {
+            oiio_ImageBufAlgo_saturate(
+                self.as_raw_ptr_mut(),
+                source.as_raw_ptr(),
+                scale,
+                options.first_channel as _,
+                options.region.clone().into(),
+                options.thread_count as _,
+                &raw mut is_ok as _,
+            );
+
+            is_ok.assume_init()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn saturate() -> Result<()> {
+        let mut image_buffer =
+            ImageBuffer::from_file(Utf8Path::new("assets/j0.3toD__F16_RGBA.exr"))?;
+
+        image_buffer.saturate(0.0)?;
+
+        assert!(image_buffer.is_monochrome_with(
+            1.0e-3,
+            &algorithms::Options {
+                region: Region::Bounds({
+                    let mut bounds = image_buffer.data_window();
+                    bounds.set_channel(0..3);
+                    bounds
+                }),
+                ..Default::default()
+            }
+        ));
+
+        image_buffer.write(Utf8Path::new("target/saturate.exr"))
+    }
+}
@@ -0,0 +1,172 @@
+use crate::{algorithms::*, *};
+
+/// The kernel used to blur the image by
+/// [`unsharp_mask()`](ImageBuffer::unsharp_mask).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UnsharpMaskKernel {
+    /// Blur with a kernel created from the given filter.
+    Filter(PixelFilter2D),
+    /// Use a median filter instead of a blur. This is better at preserving
+    /// edges but not a true unsharp mask.
+    Median,
+}
+
+impl Default for UnsharpMaskKernel {
+    fn default() -> Self {
+        Self::Filter(PixelFilter2D::Gaussian)
+    }
+}
+
+impl From<UnsharpMaskKernel> for &str {
+    fn from(kernel: UnsharpMaskKernel) -> Self {
+        match kernel {
+            UnsharpMaskKernel::Filter(filter) => filter.into(),
+            UnsharpMaskKernel::Median => "median",
+        }
+    }
+}
+
+/// Optional parameters for [`ImageBuffer`]'s
+/// [`replace_by_unsharp_mask_with()`](ImageBuffer::replace_by_unsharp_mask_with)
+/// and [`unsharp_mask_with()`](ImageBuffer::unsharp_mask_with) methods.
+#[derive(Clone)]
+pub struct UnsharpMaskOptions {
+    /// The kernel used to blur the image. Defaults to a
+    /// [`Gaussian`](PixelFilter2D::Gaussian).
+    pub kernel: UnsharpMaskKernel,
+    /// The width of the kernel in pixels.
+    pub width: f32,
+    /// The multiplier applied to the difference between the image and its
+    /// blurred version.
+    pub contrast: f32,
+    /// Differences below this value are ignored (set to zero). This avoids
+    /// sharpening noise.
+    pub threshold: f32,
+    /// See the [Region of Interest](module@algorithms#region-of-interest)
+    /// section in the [module@algorithms] module. .
+    pub region: Region,
+    /// See the [Multithreading](module@algorithms#multithreading) section
+    /// in the [module@algorithms] module.
+    pub thread_count: u16,
+}
+
+impl Default for UnsharpMaskOptions {
+    fn default() -> Self {
+        Self {
+            kernel: UnsharpMaskKernel::default(),
+            width: 3.0,
+            contrast: 1.0,
+            threshold: 0.0,
+            region: Region::default(),
+            thread_count: 0,
+        }
+    }
+}
+
+/// # Unsharp Mask
+///
+/// Sharpen the image by adding back the difference between the image and a
+/// blurred version of it.
+///
+/// I.e. the result is `source + contrast * threshold(source - blur(source))`.
+impl ImageBuffer {
+    #[named]
+    pub fn replace_by_unsharp_mask(&mut self, source: &ImageBuffer) -> Result<&mut Self> {
+        let is_ok = self.unsharp_mask_ffi(source, &UnsharpMaskOptions::default());
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+
+    #[named]
+    pub fn replace_by_unsharp_mask_with(
+        &mut self,
+        source: &ImageBuffer,
+        options: &UnsharpMaskOptions,
+    ) -> Result<&mut Self> {
+        let is_ok = self.unsharp_mask_ffi(source, options);
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+
+    #[named]
+    pub fn unsharp_mask(&mut self) -> Result<&mut Self> {
+        let mut image_buffer = ImageBuffer::new();
+        let is_ok = image_buffer.unsharp_mask_ffi(self, &UnsharpMaskOptions::default());
+        *self = image_buffer;
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+
+    #[named]
+    pub fn unsharp_mask_with(&mut self, options: &UnsharpMaskOptions) -> Result<&mut Self> {
+        let mut image_buffer = ImageBuffer::new();
+        let is_ok = image_buffer.unsharp_mask_ffi(self, options);
+        *self = image_buffer;
+
+        self.mut_self_or_error(is_ok, function_name!())
+    }
+}
+
+impl ImageBuffer {
+    #[inline]
+    fn unsharp_mask_ffi(&mut self, source: &ImageBuffer, options: &UnsharpMaskOptions) -> bool {
+        let mut is_ok = MaybeUninit::<bool>::uninit();
+
+        let kernel: &str = options.kernel.into();
+
+        unsafe {
+            oiio_ImageBufAlgo_unsharp_mask(
+                self.as_raw_ptr_mut(),
+                source.as_raw_ptr(),
+                StringView::from(kernel).as_raw_ptr() as _,
+                options.width,
+                options.contrast,
+                options.threshold,
+                options.region.clone().into(),
+                options.thread_count as _,
+                &raw mut is_ok as _,
+            );
+
+            is_ok.assume_init()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{algorithms::*, *};
+
+    #[test]
+    fn unsharp_mask() -> Result<()> {
+        let mut image_buffer =
+            ImageBuffer::from_file(Utf8Path::new("assets/j0.3toD__F16_RGBA.exr"))?;
+
+        image_buffer.unsharp_mask_with(&UnsharpMaskOptions {
+            width: 5.0,
+            contrast: 2.0,
+            ..Default::default()
+        })?;
+
+        image_buffer.write(Utf8Path::new("target/unsharp_mask.exr"))?;
+
+        // A vertical edge from 0.25 to 0.75 gets over- and undershoots next
+        // to it. Flat areas away from it are not changed.
+        let mut image_buffer =
+            ImageBuffer::from_fill(&[0.25], &Bounds::new(0..16, 0..16, 0..1, Some(0..1)))?;
+        for y in 0..16 {
+            for x in 8..16 {
+                image_buffer.set_pixel(x, y, None, &[0.75]);
+            }
+        }
+
+        image_buffer.unsharp_mask()?;
+
+        let value = |x| image_buffer.get_pixel(x, 8, None, WrapMode::Black)[0];
+        assert!(value(7) < 0.25);
+        assert!(0.75 < value(8));
+        assert!((0.25 - value(3)).abs() < 1.0e-6);
+        assert!((0.75 - value(12)).abs() < 1.0e-6);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,312 @@
+use crate::*;
+use anyhow::{anyhow, Result};
+use core::{ffi::c_int, mem::MaybeUninit};
+
+/// The tile size used for MIP-mapped files written from untiled images.
+const TILE_SIZE: c_int = 64;
+
+/// # MIP-Levels
+///
+/// A MIP-mapped file stores a pyramid of successively half-resolution copies
+/// of an image. Level `0` is the full resolution image.
+impl ImageBuffer {
+    /// Returns an iterator over all MIP-levels of the file this `ImageBuffer`
+    /// was read from, starting with level `0`.
+    ///
+    /// Each level is read lazily. It uses the same [`ImageCache`] as `self`,
+    /// if any.
+    ///
+    /// An `ImageBuffer` that does not refer to a MIP-mapped file yields a
+    /// single level: a copy of itself.
+    pub fn mip_levels(&self) -> MipLevels<'_> {
+        MipLevels {
+            image_buffer: self,
+            mip_level: 0,
+            mip_level_count: self.mip_level_count().unwrap_or(1),
+        }
+    }
+
+    /// Switch to MIP-level `mip_level` of the file this `ImageBuffer` was read
+    /// from.
+    ///
+    /// The current subimage and [`ImageCache`] are kept. Any changes made to
+    /// the pixels are lost.
+    #[named]
+    pub fn set_mip_level(&mut self, mip_level: u32) -> Result<&mut Self> {
+        let name = self.name().ok_or_else(|| {
+            anyhow!(
+                "[fn {}] The image does not refer to a file",
+                function_name!()
+            )
+        })?;
+
+        if self.mip_level_count().unwrap_or(1) <= mip_level {
+            return Err(anyhow!(
+                "[fn {}] '{name}' has no MIP-level {mip_level}",
+                function_name!()
+            ));
+        }
+
+        *self = Self::from_file_with(
+            Utf8Path::new(&name),
+            &FromFileOptions {
+                sub_image: self.sub_image(),
+                mip_level,
+                image_cache: self.cache(),
+                ..Default::default()
+            },
+        )?;
+
+        Ok(self)
+    }
+
+    /// Write `mip_levels` as the MIP-levels of a single file.
+    ///
+    /// The levels must be ordered from the highest resolution to the lowest,
+    /// e.g. as returned by
+    /// [`build_mip_pyramid()`](ImageBuffer::build_mip_pyramid):
+    ///
+    /// ```ignore
+    /// let pyramid = image_buffer.build_mip_pyramid(None)?;
+    /// ImageBuffer::write_mip_levels(file_name, &pyramid.iter().collect::<Vec<_>>())?;
+    /// ```
+    ///
+    /// Untiled levels are written with 64×64 tiles, as e.g. OpenEXR can only
+    /// store MIP-maps in tiled files.
+    ///
+    /// Returns an error if the file format can not store MIP-maps.
+    #[named]
+    pub fn write_mip_levels(file_name: &Utf8Path, mip_levels: &[&ImageBuffer]) -> Result<()> {
+        if mip_levels.is_empty() {
+            return Err(anyhow!("[fn {}] No MIP-levels to write", function_name!()));
+        }
+
+        let output = ImageOutput::create(file_name)
+            .ok_or_else(|| anyhow!("[fn {}] {}", function_name!(), global_error()))?;
+
+        let output_error = || anyhow!("[fn {}] {}", function_name!(), output.error());
+
+        if 1 < mip_levels.len() && !output.supports(c"mipmap") {
+            return Err(anyhow!(
+                "[fn {}] The format of '{file_name}' does not support MIP-maps",
+                function_name!()
+            ));
+        }
+
+        let is_tiled = output.supports(c"tiles");
+
+        let mut image_specs = VecImageSpec::new();
+        mip_levels
+            .iter()
+            .for_each(|image_buffer| image_specs.push(image_buffer.image_spec_ptr()));
+
+        for (index, image_buffer) in mip_levels.iter().enumerate() {
+            let image_spec_ptr = image_specs.get_mut(index);
+
+            unsafe {
+                if is_tiled {
+                    set_default_tile_size(image_spec_ptr);
+                }
+
+                if 0 == index {
+                    // Tells the OpenEXR writer to expect further levels.
+                    let value = c"Plain Texture".as_ptr();
+                    oiio_ImageSpec_attribute(
+                        image_spec_ptr,
+                        c"textureformat".as_ptr(),
+                        TypeDesc::STRING.into(),
+                        &raw const value as _,
+                    );
+                }
+            }
+
+            let open_mode = if 0 == index {
+                oiio_OpenMode::oiio_OpenMode_Create
+            } else {
+                oiio_OpenMode::oiio_OpenMode_AppendMIPLevel
+            };
+
+            if !output.open(image_spec_ptr, open_mode) {
+                return Err(output_error());
+            }
+
+            if !output.write_image_buffer(image_buffer) {
+                return Err(match image_buffer.error(true) {
+                    Some(error) => anyhow!("[fn {}] {error}", function_name!()),
+                    None => output_error(),
+                });
+            }
+        }
+
+        if !output.close() {
+            return Err(output_error());
+        }
+
+        Ok(())
+    }
+}
+
+/// # MIP-Pyramid
+#[cfg(feature = "algorithms")]
+impl ImageBuffer {
+    /// Build a MIP-pyramid from this image.
+    ///
+    /// The first element is a copy of `self`. Each following level halves the
+    /// width and height of the previous one (rounding down) until a 1×1 image
+    /// is reached.
+    ///
+    /// Each level is resized from the previous one using `filter`. If `filter`
+    /// is `None`, [`Lanczos3`](algorithms::PixelFilter2D::Lanczos3) is used.
+    ///
+    /// Use [`write_mip_levels()`](ImageBuffer::write_mip_levels) to store the
+    /// result in a single file.
+    #[named]
+    pub fn build_mip_pyramid(
+        &self,
+        filter: Option<algorithms::Filter2D>,
+    ) -> Result<Vec<ImageBuffer>> {
+        let data_window = self.data_window();
+
+        if 1 != data_window.depth() {
+            return Err(anyhow!("[fn {}] Image is not 2D", function_name!()));
+        }
+
+        let mut width = data_window.width();
+        let mut height = data_window.height();
+
+        let mut mip_levels = vec![self.clone()];
+
+        while 1 < width || 1 < height {
+            width = (width / 2).max(1);
+            height = (height / 2).max(1);
+
+            let mut mip_level = ImageBuffer::new();
+            mip_level.replace_by_resize_with(
+                mip_levels.last().unwrap(),
+                &algorithms::ResizeOptions {
+                    region: Bounds::new(
+                        0..width as _,
+                        0..height as _,
+                        0..1,
+                        Some(0..self.channel_count()),
+                    )
+                    .into(),
+                    filter,
+                    ..Default::default()
+                },
+            )?;
+
+            mip_levels.push(mip_level);
+        }
+
+        Ok(mip_levels)
+    }
+}
+
+/// An iterator over the MIP-levels of a file.
+///
+/// Returned by [`ImageBuffer::mip_levels()`].
+pub struct MipLevels<'a> {
+    image_buffer: &'a ImageBuffer,
+    mip_level: u32,
+    mip_level_count: u32,
+}
+
+impl Iterator for MipLevels<'_> {
+    type Item = Result<ImageBuffer>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.mip_level_count <= self.mip_level {
+            return None;
+        }
+
+        let mip_level = self.mip_level;
+        self.mip_level += 1;
+
+        Some(match self.image_buffer.name() {
+            Some(name) => ImageBuffer::from_file_with(
+                Utf8Path::new(&name),
+                &FromFileOptions {
+                    sub_image: self.image_buffer.sub_image(),
+                    mip_level,
+                    image_cache: self.image_buffer.cache(),
+                    ..Default::default()
+                },
+            ),
+            None => Ok(self.image_buffer.clone()),
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.mip_level_count - self.mip_level) as usize;
+
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for MipLevels<'_> {}
+
+/// Sets a tile size on an untiled `image_spec`.
+unsafe fn set_default_tile_size(image_spec: *mut oiio_ImageSpec_t) {
+    let mut tile_width = MaybeUninit::<c_int>::uninit();
+    oiio_ImageSpec_get_tile_width(image_spec, &raw mut tile_width as _);
+
+    if 0 == tile_width.assume_init() {
+        oiio_ImageSpec_set_tile_width(image_spec, TILE_SIZE);
+        oiio_ImageSpec_set_tile_height(image_spec, TILE_SIZE);
+        oiio_ImageSpec_set_tile_depth(image_spec, 1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{algorithms::*, *};
+
+    #[test]
+    fn mip_levels() -> Result<()> {
+        let image_buffer = ImageBuffer::from_fill(
+            &[0.25, 0.5, 1.0],
+            &Bounds::new(0..16, 0..8, 0..1, Some(0..3)),
+        )?;
+
+        let pyramid = image_buffer.build_mip_pyramid(Some(PixelFilter2D::Box.into()))?;
+        assert_eq!(
+            vec![(16, 8), (8, 4), (4, 2), (2, 1), (1, 1)],
+            pyramid
+                .iter()
+                .map(|level| (level.data_window().width(), level.data_window().height()))
+                .collect::<Vec<_>>()
+        );
+
+        let pyramid = pyramid.iter().collect::<Vec<_>>();
+
+        let file_name = Utf8Path::new("target/mip_levels.exr");
+        ImageBuffer::write_mip_levels(file_name, &pyramid)?;
+
+        let mut image_buffer = ImageBuffer::from_file(file_name)?;
+        assert_eq!(Some(5), image_buffer.mip_level_count());
+
+        let mip_levels = image_buffer.mip_levels().collect::<Result<Vec<_>>>()?;
+        assert_eq!(5, mip_levels.len());
+        assert_eq!(Some(2), mip_levels[2].mip_level());
+        assert_eq!(4, mip_levels[2].data_window().width());
+
+        image_buffer.set_mip_level(3)?;
+        assert_eq!(2, image_buffer.data_window().width());
+        assert!(image_buffer.set_mip_level(5).is_err());
+
+        // A box filter preserves the constant color.
+        assert_eq!(
+            vec![0.25, 0.5, 1.0],
+            image_buffer.get_pixel(0, 0, None, WrapMode::Black)
+        );
+
+        // PNG can not store MIP-maps.
+        assert!(
+            ImageBuffer::write_mip_levels(Utf8Path::new("target/mip_levels.png"), &pyramid)
+                .is_err()
+        );
+
+        Ok(())
+    }
+}
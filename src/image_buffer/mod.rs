@@ -12,9 +12,20 @@ mod adapters;
 pub use adapters::*;
 
 mod internal;
+
+mod mip_levels;
+pub use mip_levels::*;
+
 mod pixels;
 pub use pixels::*;
 
+mod sub_images;
+
+mod thumbnail;
+
+mod typed_image;
+pub use typed_image::*;
+
 /// Convenience type alias for developers familiar with the OpenImageIO C++ API.
 pub type ImageBuf = ImageBuffer;
 
@@ -305,6 +316,14 @@ impl ImageBuffer {
         }
     }
 
+    /// Return the color space the pixels of this `ImageBuffer` are in.
+    ///
+    /// This is the value of the `oiio:ColorSpace` attribute of its
+    /// [`ImageSpec`]. Returns `None` if the attribute is not set.
+    pub fn color_space(&self) -> Option<String> {
+        self.string_attribute(c"oiio:ColorSpace")
+    }
+
     /// Return the index of the subimage within the file that the `ImageBuffer`
     /// refers to.
     ///
@@ -419,6 +438,26 @@ impl ImageBuffer {
         }
     }
 
+    /// Returns the index of the channel called `name` or `None` if there is no
+    /// such channel.
+    ///
+    /// # C++
+    ///
+    /// The C++ method is `spec().channelindex()`.
+    pub fn channel_index(&self, name: &str) -> Option<u32> {
+        let name = std::ffi::CString::new(name).ok()?;
+
+        let mut index = MaybeUninit::<c_int>::uninit();
+
+        let index = unsafe {
+            oiio_ImageSpec_channelindex(self.image_spec_ptr(), name.as_ptr(), &raw mut index as _);
+
+            index.assume_init()
+        };
+
+        u32::try_from(index).ok()
+    }
+
     /// Return pixel *data window* for this `ImageBuffer`.
     ///
     /// For C++ Developers
@@ -466,27 +505,170 @@ impl ImageBuffer {
             .into()
     }
 
-    /*pub fn image_spec(&self) -> ImageSpec {
-        let mut image_spec_ptr = MaybeUninit::<*mut oiio_ImageSpec_t>::uninit();
+    /// Return the [`ImageSpec`] that describes this `ImageBuffer`.
+    ///
+    /// This is a copy. Changing it does not affect the `ImageBuffer`.
+    ///
+    /// For C++ Developers
+    ///
+    /// [The C++ version](https://openimageio.readthedocs.io/en/latest/imagebuf.html#_CPPv4NK4OIIO8ImageBuf4specEv)
+    /// of this is called [`spec()`](Self::spec).
+    pub fn image_spec(&self) -> ImageSpec {
+        let mut image_spec_ptr = MaybeUninit::<*const oiio_ImageSpec_t>::uninit();
 
-        ImageSpecInternal {
-            ptr: unsafe {
-                oiio_ImageBuf_spec(self.ptr, &mut image_spec_ptr as *mut _ as _);
+        // The spec is owned by the `ImageBuf`, so we must not wrap it in an
+        // `ImageSpecInternal` (which would free it on drop).
+        ImageSpec::from_raw_ptr(unsafe {
+            oiio_ImageBuf_spec(self.ptr, &raw mut image_spec_ptr as _);
 
-                image_spec_ptr.assume_init()
-            },
-        }
-        .into()
-    }*/
+            image_spec_ptr.assume_init()
+        })
+    }
 
-    /*
-    pub fn spec(&self) -> ImageSpec {
-        let mut dst = MaybeUninit::<ImageSpec>::uninit();
-    }*/
+    /// Return the [`ImageSpec`] that describes the file this `ImageBuffer` was
+    /// read from.
+    ///
+    /// This may differ from [`image_spec()`](Self::image_spec) in the data
+    /// format, e.g. if the pixels were converted to a different type when they
+    /// were read. For an `ImageBuffer` not backed by a file, it is identical to
+    /// `image_spec()`.
+    pub fn native_image_spec(&self) -> ImageSpec {
+        let mut image_spec_ptr = MaybeUninit::<*const oiio_ImageSpec_t>::uninit();
+
+        ImageSpec::from_raw_ptr(unsafe {
+            oiio_ImageBuf_nativespec(self.ptr, &raw mut image_spec_ptr as _);
+
+            image_spec_ptr.assume_init()
+        })
+    }
 
     pub fn cache(&self) -> Option<ImageCache> {
         self.image_cache.clone()
     }
+
+    /// Return the [`Orientation`] of the image, as given by its `Orientation`
+    /// metadata.
+    ///
+    /// This is how the pixels should be transformed for display. Use
+    /// [`re_orient()`](Self::re_orient) to actually apply it.
+    pub fn orientation(&self) -> Orientation {
+        let mut orientation = MaybeUninit::<c_int>::uninit();
+
+        unsafe {
+            oiio_ImageBuf_orientation(self.ptr, &raw mut orientation as _);
+
+            orientation.assume_init()
+        }
+        .into()
+    }
+}
+
+/// # Pixel Access
+///
+/// Coordinates are absolute, i.e. they take the origin of the
+/// [`data_window()`](ImageBuffer::data_window) into account. Where the pixel
+/// lookup falls outside the data window, `wrap_mode` determines the values
+/// returned.
+///
+/// The interpolating methods use pixel-center conventions: the center of the
+/// pixel `(x, y)` is at `(x + 0.5, y + 0.5)`.
+impl ImageBuffer {
+    /// Retrieve the value of the pixel with the given coordinates, converted
+    /// to `f32`.
+    ///
+    /// If `z` is `None` it is assumed to be zero (the default image plane).
+    pub fn get_pixel(&self, x: i32, y: i32, z: Option<i32>, wrap_mode: WrapMode) -> Vec<f32> {
+        let mut pixel = vec![0.0; self.channel_count() as _];
+
+        self.get_pixel_into(x, y, z, wrap_mode, &mut pixel);
+
+        pixel
+    }
+
+    /// Like [`get_pixel()`](ImageBuffer::get_pixel) but writes the value into
+    /// `pixel`.
+    ///
+    /// The number of channels copied is the minimum of the `pixel` slice's
+    /// length and the actual number of channels in the image.
+    pub fn get_pixel_into(
+        &self,
+        x: i32,
+        y: i32,
+        z: Option<i32>,
+        wrap_mode: WrapMode,
+        pixel: &mut [f32],
+    ) {
+        unsafe {
+            oiio_ImageBuf_getpixel(
+                self.ptr,
+                x,
+                y,
+                z.unwrap_or(0),
+                pixel.as_mut_ptr(),
+                pixel.len() as _,
+                wrap_mode.into(),
+            );
+        }
+    }
+
+    /// Sample the image at the continuous pixel coordinates `(x, y)` using
+    /// bilinear interpolation.
+    pub fn interpolate_pixel(&self, x: f32, y: f32, wrap_mode: WrapMode) -> Vec<f32> {
+        let mut pixel = vec![0.0; self.channel_count() as _];
+
+        unsafe {
+            oiio_ImageBuf_interppixel(self.ptr, x, y, pixel.as_mut_ptr(), wrap_mode.into());
+        }
+
+        pixel
+    }
+
+    /// Sample the image at the continuous pixel coordinates `(x, y)` using
+    /// bicubic interpolation.
+    pub fn interpolate_pixel_bicubic(&self, x: f32, y: f32, wrap_mode: WrapMode) -> Vec<f32> {
+        let mut pixel = vec![0.0; self.channel_count() as _];
+
+        unsafe {
+            oiio_ImageBuf_interppixel_bicubic(self.ptr, x, y, pixel.as_mut_ptr(), wrap_mode.into());
+        }
+
+        pixel
+    }
+
+    /// Sample the image using bilinear interpolation at the *normalized device
+    /// coordinates* `(s, t)`.
+    ///
+    /// `(0, 0)` is the top left and `(1, 1)` the bottom right corner of the
+    /// [`display_window()`](ImageBuffer::display_window).
+    pub fn interpolate_pixel_ndc(&self, s: f32, t: f32, wrap_mode: WrapMode) -> Vec<f32> {
+        let mut pixel = vec![0.0; self.channel_count() as _];
+
+        unsafe {
+            oiio_ImageBuf_interppixel_NDC(self.ptr, s, t, pixel.as_mut_ptr(), wrap_mode.into());
+        }
+
+        pixel
+    }
+
+    /// Sample the image using bicubic interpolation at the *normalized device
+    /// coordinates* `(s, t)`.
+    ///
+    /// See [`interpolate_pixel_ndc()`](ImageBuffer::interpolate_pixel_ndc).
+    pub fn interpolate_pixel_bicubic_ndc(&self, s: f32, t: f32, wrap_mode: WrapMode) -> Vec<f32> {
+        let mut pixel = vec![0.0; self.channel_count() as _];
+
+        unsafe {
+            oiio_ImageBuf_interppixel_bicubic_NDC(
+                self.ptr,
+                s,
+                t,
+                pixel.as_mut_ptr(),
+                wrap_mode.into(),
+            );
+        }
+
+        pixel
+    }
 }
 
 /// # Setters
@@ -541,6 +723,15 @@ impl ImageBuffer {
         }
     }
 
+    /// Set the `Orientation` metadata of the image.
+    ///
+    /// This does not alter the pixels.
+    pub fn set_orientation(&mut self, orientation: Orientation) {
+        unsafe {
+            oiio_ImageBuf_set_orientation(self.ptr, orientation as _);
+        }
+    }
+
     pub fn set_write_pixel_layout(&mut self, pixel_layout: PixelLayout) {
         match pixel_layout {
             PixelLayout::Scanline => unsafe {
@@ -557,12 +748,32 @@ impl ImageBuffer {
         }
     }
 
-    pub fn set_write_format(&mut self) {
-        unimplemented!()
+    /// Set the pixel data format that will be used for subsequent
+    /// [`write()`](Self::write) calls.
+    ///
+    /// This does not alter the pixels in memory. A `format` with a
+    /// [`base_type`](TypeDesc::base_type) of `None` means to use the format of
+    /// the pixels in memory.
+    pub fn set_write_format(&mut self, format: TypeDesc) {
+        self.set_write_per_channel_format(&[format]);
     }
 
-    pub fn set_write_per_channel_format(&mut self, _channel_format: &[TypeDesc]) {
-        unimplemented!()
+    /// Set the per-channel pixel data formats that will be used for subsequent
+    /// [`write()`](Self::write) calls.
+    ///
+    /// E.g. to write an EXR with `F16` RGB and `F32` Z channels pass
+    /// `&[TypeDesc::F16, TypeDesc::F16, TypeDesc::F16, TypeDesc::F32]`.
+    ///
+    /// If `channel_format` contains a single element it is used for all
+    /// channels. If the file format does not support per-channel formats, the
+    /// widest of the formats is used for all channels.
+    pub fn set_write_per_channel_format(&mut self, channel_format: &[TypeDesc]) {
+        unsafe {
+            oiio_ImageBuf_set_write_format(
+                self.as_raw_ptr_mut(),
+                VecTypeDesc::from(channel_format).as_raw_ptr(),
+            );
+        }
     }
 }
 
@@ -574,6 +785,50 @@ impl ImageBuffer {
     pub fn roi(&self) -> Region {
         self.data_window()
     }
+
+    /// Alias for [`image_spec()`](Self::image_spec).
+    #[inline(always)]
+    pub fn spec(&self) -> ImageSpec {
+        self.image_spec()
+    }
+
+    /// Alias for [`native_image_spec()`](Self::native_image_spec).
+    #[inline(always)]
+    pub fn nativespec(&self) -> ImageSpec {
+        self.native_image_spec()
+    }
+
+    /// Alias for [`get_pixel()`](Self::get_pixel).
+    #[inline(always)]
+    pub fn getpixel(&self, x: i32, y: i32, z: Option<i32>, wrap_mode: WrapMode) -> Vec<f32> {
+        self.get_pixel(x, y, z, wrap_mode)
+    }
+
+    /// Alias for [`interpolate_pixel()`](Self::interpolate_pixel).
+    #[inline(always)]
+    pub fn interppixel(&self, x: f32, y: f32, wrap_mode: WrapMode) -> Vec<f32> {
+        self.interpolate_pixel(x, y, wrap_mode)
+    }
+
+    /// Alias for [`interpolate_pixel_bicubic()`](Self::interpolate_pixel_bicubic).
+    #[inline(always)]
+    pub fn interppixel_bicubic(&self, x: f32, y: f32, wrap_mode: WrapMode) -> Vec<f32> {
+        self.interpolate_pixel_bicubic(x, y, wrap_mode)
+    }
+
+    /// Alias for [`interpolate_pixel_ndc()`](Self::interpolate_pixel_ndc).
+    #[allow(non_snake_case)]
+    #[inline(always)]
+    pub fn interppixel_NDC(&self, s: f32, t: f32, wrap_mode: WrapMode) -> Vec<f32> {
+        self.interpolate_pixel_ndc(s, t, wrap_mode)
+    }
+
+    /// Alias for [`interpolate_pixel_bicubic_ndc()`](Self::interpolate_pixel_bicubic_ndc).
+    #[allow(non_snake_case)]
+    #[inline(always)]
+    pub fn interppixel_bicubic_NDC(&self, s: f32, t: f32, wrap_mode: WrapMode) -> Vec<f32> {
+        self.interpolate_pixel_bicubic_ndc(s, t, wrap_mode)
+    }
 }
 
 /// # C++ API Setter Aliases
@@ -652,6 +907,63 @@ impl ImageBuffer {
     pub(crate) fn as_raw_ptr(&self) -> *const oiio_ImageBuf_t {
         self.ptr
     }
+
+    fn image_spec_ptr(&self) -> *const oiio_ImageSpec_t {
+        let mut image_spec_ptr = MaybeUninit::<*const oiio_ImageSpec_t>::uninit();
+
+        unsafe {
+            oiio_ImageBuf_spec(self.ptr, &raw mut image_spec_ptr as _);
+
+            image_spec_ptr.assume_init()
+        }
+    }
+
+    /// Returns the value of the string attribute `name` of the [`ImageSpec`]
+    /// or `None` if it is not set or empty.
+    pub(crate) fn string_attribute(&self, name: &core::ffi::CStr) -> Option<String> {
+        let mut value = MaybeUninit::<*const core::ffi::c_char>::uninit();
+        let mut is_ok = MaybeUninit::<bool>::uninit();
+
+        let value = unsafe {
+            oiio_ImageSpec_getattribute(
+                self.image_spec_ptr(),
+                name.as_ptr(),
+                TypeDesc::STRING.into(),
+                &raw mut value as _,
+                false,
+                &raw mut is_ok as _,
+            );
+
+            if !is_ok.assume_init() {
+                return None;
+            }
+
+            core::ffi::CStr::from_ptr(value.assume_init())
+        };
+
+        if value.is_empty() {
+            None
+        } else {
+            Some(value.to_string_lossy().into_owned())
+        }
+    }
+
+    /// Sets the string attribute `name` of the [`ImageSpec`].
+    pub(crate) fn set_string_attribute(&mut self, name: &core::ffi::CStr, value: &str) {
+        let value = std::ffi::CString::new(value.replace('\0', "")).unwrap_or_default();
+        let value_ptr = value.as_ptr();
+        let mut image_spec_ptr = MaybeUninit::<*mut oiio_ImageSpec_t>::uninit();
+
+        unsafe {
+            oiio_ImageBuf_specmod(self.ptr, &raw mut image_spec_ptr as _);
+            oiio_ImageSpec_attribute(
+                image_spec_ptr.assume_init(),
+                name.as_ptr(),
+                TypeDesc::STRING.into(),
+                &raw const value_ptr as _,
+            );
+        }
+    }
 }
 
 #[derive(Clone, Default, Debug)]
@@ -677,6 +989,50 @@ impl From<WrapMode> for oiio_WrapMode {
     }
 }
 
+/// How the pixels of an image need to be transformed for display.
+///
+/// The values match those of the `Orientation` metadata in EXIF/TIFF.
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq, Hash)]
+#[repr(C)]
+pub enum Orientation {
+    /// The image is displayed as is.
+    #[default]
+    Normal = 1,
+    /// The image is mirrored horizontally (see
+    /// [`flop()`](ImageBuffer::flop)).
+    FlippedHorizontally = 2,
+    /// The image is rotated 180°.
+    Rotated180 = 3,
+    /// The image is mirrored vertically (see [`flip()`](ImageBuffer::flip)).
+    FlippedVertically = 4,
+    /// The image is mirrored about the top left to bottom right diagonal (see
+    /// [`transpose()`](ImageBuffer::transpose)).
+    Transposed = 5,
+    /// The image is rotated 90° clockwise.
+    Rotated90Clockwise = 6,
+    /// The image is mirrored about the top right to bottom left diagonal.
+    Transversed = 7,
+    /// The image is rotated 90° counter-clockwise.
+    Rotated90CounterClockwise = 8,
+}
+
+impl From<c_int> for Orientation {
+    /// Values outside the valid range of `1..=8` map to
+    /// [`Normal`](Orientation::Normal).
+    fn from(orientation: c_int) -> Self {
+        match orientation {
+            2 => Self::FlippedHorizontally,
+            3 => Self::Rotated180,
+            4 => Self::FlippedVertically,
+            5 => Self::Transposed,
+            6 => Self::Rotated90Clockwise,
+            7 => Self::Transversed,
+            8 => Self::Rotated90CounterClockwise,
+            _ => Self::Normal,
+        }
+    }
+}
+
 /// Optional parameters for [`ImageBuffer`]'s
 /// [`from_file_with()`](ImageBuffer::from_file_with) method.
 #[derive(Default, Debug)]
@@ -950,6 +1306,97 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn write_format() -> Result<()> {
+        let mut image_buffer =
+            ImageBuffer::from_fill(&[0.5], &Bounds::new(0..16, 0..16, 0..1, Some(0..5)))?;
+
+        image_buffer.set_write_per_channel_format(&[
+            TypeDesc::F16,
+            TypeDesc::F16,
+            TypeDesc::F16,
+            TypeDesc::F32,
+            TypeDesc::F32,
+        ]);
+        image_buffer.write(Utf8Path::new("target/write_format.exr"))?;
+
+        let image_buffer = ImageBuffer::from_file(Utf8Path::new("target/write_format.exr"))?;
+
+        assert_eq!(
+            ChannelFormat::PerChannel(vec![
+                BaseType::F16,
+                BaseType::F16,
+                BaseType::F16,
+                BaseType::F32,
+                BaseType::F32
+            ]),
+            image_buffer.native_image_spec().channel_format
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn write_per_channel_format_from_image_spec() -> Result<()> {
+        let channel_format =
+            ChannelFormat::PerChannel(vec![BaseType::F16, BaseType::F16, BaseType::F32]);
+
+        let image_buffer = ImageBuffer::new_with(
+            &ImageSpec {
+                width: 16,
+                height: 16,
+                depth: 1,
+                display_window_width: 16,
+                display_window_height: 16,
+                display_window_depth: 1,
+                channel_format: channel_format.clone(),
+                channel_name: vec![ustr("R"), ustr("G"), ustr("Z")],
+                ..Default::default()
+            },
+            InitializePixels::Yes,
+        );
+        image_buffer.write(Utf8Path::new("target/write_per_channel_format.exr"))?;
+
+        let image_buffer =
+            ImageBuffer::from_file(Utf8Path::new("target/write_per_channel_format.exr"))?;
+
+        assert_eq!(
+            channel_format,
+            image_buffer.native_image_spec().channel_format
+        );
+
+        Ok(())
+    }
+
+    #[cfg(feature = "half")]
+    #[test]
+    fn pixels_f16() -> Result<()> {
+        use half::f16;
+
+        let mut image_buffer =
+            ImageBuffer::from_file(Utf8Path::new("assets/j0.3toD__F16_RGBA.exr"))?;
+
+        assert_eq!(
+            ChannelFormat::Uniform(BaseType::F16, 4),
+            image_buffer.native_image_spec().channel_format
+        );
+
+        let bounds = image_buffer.data_window();
+        let mut pixels: Vec<f16> = image_buffer.pixels(&Region::All)?;
+        assert_eq!(bounds.pixel_count() * 4, pixels.len());
+
+        pixels
+            .iter_mut()
+            .for_each(|value| *value = f16::ONE - *value);
+        image_buffer.set_pixels(&pixels, &Region::All)?;
+
+        // The buffer keeps its native `f16` pixels.
+        assert_eq!(Some(BaseType::F16), image_buffer.type_desc().base_type);
+        assert_eq!(pixels, image_buffer.pixels(&Region::All)?);
+
+        Ok(())
+    }
+
     #[test]
     fn data_window() -> Result<()> {
         let image_buffer = ImageBuffer::new();
@@ -959,6 +1406,56 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn pixel_access() -> Result<()> {
+        let mut image_buffer = ImageBuffer::from_slice_with(
+            2,
+            1,
+            1,
+            BaseType::F32,
+            None,
+            &[0.0f32, 1.0],
+            &FromSliceOptions {
+                origin: (10, 20),
+                ..Default::default()
+            },
+        )?;
+
+        image_buffer.set_display_to_data_window();
+
+        assert_eq!(
+            vec![1.0],
+            image_buffer.get_pixel(11, 20, None, WrapMode::Black)
+        );
+        assert_eq!(
+            vec![0.0],
+            image_buffer.get_pixel(12, 20, None, WrapMode::Black)
+        );
+        assert_eq!(
+            vec![1.0],
+            image_buffer.get_pixel(12, 20, None, WrapMode::Clamp)
+        );
+
+        let mut pixel = [-1.0; 2];
+        image_buffer.get_pixel_into(10, 20, None, WrapMode::Black, &mut pixel);
+        assert_eq!([0.0, -1.0], pixel);
+
+        // Halfway between the pixel centers.
+        assert_eq!(
+            vec![0.5],
+            image_buffer.interpolate_pixel(11.0, 20.5, WrapMode::Clamp)
+        );
+        assert_eq!(
+            vec![0.5],
+            image_buffer.interpolate_pixel_ndc(0.5, 0.5, WrapMode::Clamp)
+        );
+
+        let value = image_buffer.interpolate_pixel_bicubic(11.0, 20.5, WrapMode::Clamp)[0];
+        assert!((0.5 - value).abs() < 1.0e-6);
+
+        Ok(())
+    }
+
     /*
     #[test]
     fn pixels() -> Result<()> {
@@ -9,6 +9,12 @@ pub struct PixelsOptions {
     z_stride: Option<u32>,
 }*/
 
+/// A primitive type pixel data can be stored in and the [`BaseType`] it
+/// corresponds to.
+pub trait PixelData: Copy + Default + Send + Sync + 'static {
+    const BASE_TYPE: BaseType;
+}
+
 pub trait Pixels<T> {
     /// Retrieve a region of pixels.
     ///
@@ -64,6 +70,10 @@ pub trait Pixels<T> {
 
 macro_rules! pixels {
     ($rust_type:ty, $cspan_type:ty, $base_type:expr, $fn_name:ident) => {
+        impl PixelData for $rust_type {
+            const BASE_TYPE: BaseType = $base_type;
+        }
+
         impl Pixels<$rust_type> for ImageBuffer {
             /// Get a region of pixels from the image buffer.
             fn pixels(&self, region: &Region) -> Result<Vec<$rust_type>> {
@@ -86,7 +96,7 @@ macro_rules! pixels {
                     oiio_ImageBuf_get_pixels(
                         self.ptr,
                         bounds.clone().into(),
-                        $base_type,
+                        <$rust_type as PixelData>::BASE_TYPE.into(),
                         data.as_mut_ptr() as _,
                         &raw mut is_ok as _,
                     );
@@ -137,158 +147,23 @@ macro_rules! pixels {
     };
 }
 
-pixels!(
-    u8,
-    CspanU8,
-    oiio_BASETYPE::oiio_BASETYPE_UINT8,
-    oiio_ImageBuf_set_pixels_u8
-);
-pixels!(
-    u16,
-    CspanU16,
-    oiio_BASETYPE::oiio_BASETYPE_UINT16,
-    oiio_ImageBuf_set_pixels_u16
-);
-pixels!(
-    u32,
-    CspanU32,
-    oiio_BASETYPE::oiio_BASETYPE_UINT32,
-    oiio_ImageBuf_set_pixels_u32
-);
+pixels!(u8, CspanU8, BaseType::U8, oiio_ImageBuf_set_pixels_u8);
+pixels!(u16, CspanU16, BaseType::U16, oiio_ImageBuf_set_pixels_u16);
+pixels!(u32, CspanU32, BaseType::U32, oiio_ImageBuf_set_pixels_u32);
 /*pixels!(
     u64,
-    oiio_BASETYPE::oiio_BASETYPE_UINT64,
+    BaseType::U64,
     oiio_ImageBuf_set_pixels_u64
 );*/
-pixels!(
-    i8,
-    CspanI8,
-    oiio_BASETYPE::oiio_BASETYPE_INT8,
-    oiio_ImageBuf_set_pixels_u8
-);
-pixels!(
-    i16,
-    CspanI16,
-    oiio_BASETYPE::oiio_BASETYPE_INT16,
-    oiio_ImageBuf_set_pixels_u16
-);
-pixels!(
-    i32,
-    CspanI32,
-    oiio_BASETYPE::oiio_BASETYPE_INT32,
-    oiio_ImageBuf_set_pixels_u32
-);
+pixels!(i8, CspanI8, BaseType::I8, oiio_ImageBuf_set_pixels_u8);
+pixels!(i16, CspanI16, BaseType::I16, oiio_ImageBuf_set_pixels_u16);
+pixels!(i32, CspanI32, BaseType::I32, oiio_ImageBuf_set_pixels_u32);
 /*pixels!(
     i64,
-    oiio_BASETYPE::oiio_BASETYPE_INT64,
+    BaseType::I64,
     oiio_ImageBuf_set_pixels_u64
 );*/
-/*#[cfg(feature = "half")]
-pixels!(
-    f16,
-    CspanF16,
-    oiio_BASETYPE::oiio_BASETYPE_HALF,
-    oiio_ImageBuf_set_pixels_f16
-);*/
-pixels!(
-    f32,
-    CspanF32,
-    oiio_BASETYPE::oiio_BASETYPE_FLOAT,
-    oiio_ImageBuf_set_pixels_f32
-);
-pixels!(
-    f64,
-    CspanF64,
-    oiio_BASETYPE::oiio_BASETYPE_DOUBLE,
-    oiio_ImageBuf_set_pixels_f64
-);
-
 #[cfg(feature = "half")]
-impl Pixels<f16> for ImageBuffer {
-    /// Get a region of pixels from the image buffer.
-    fn pixels(&self, region: &Region) -> Result<Vec<f16>> {
-        if ImageBufferStorage::Uninitialized == self.storage() {
-            // An uninitialized image buffer has no pixels but it's not
-            // an error to ask for them.
-            return Ok(Vec::new());
-        }
-
-        let region = match region {
-            Region::All => match self.data_window() {
-                Region::All => {
-                    // If this image buffer is uninitialized, we can't
-                    // get here because
-                    // `self.storage()` will return
-                    // `ImageBufferStorage::Uninitialized`.
-                    unreachable!()
-                }
-                Region::Bounds(roi) => roi,
-            },
-            Region::Bounds(roi) => roi.clone(),
-        };
-
-        let size = region.pixel_count() * region.channel_count() as usize;
-        let mut data = Vec::<f16>::with_capacity(size);
-        let mut is_ok = std::mem::MaybeUninit::<bool>::uninit();
-
-        unsafe {
-            oiio_ImageBuf_get_pixels(
-                self.ptr,
-                region.clone().into(),
-                oiio_BASETYPE::oiio_BASETYPE_HALF,
-                data.as_mut_ptr() as _,
-                &raw mut is_ok as _,
-            );
-
-            if is_ok.assume_init() {
-                data.set_len(size);
-                Ok(data)
-            } else {
-                Err(anyhow!(self
-                    .error(true)
-                    .unwrap_or("ImageBuffer::pixels(): unknown error".into())))
-            }
-        }
-    }
-
-    fn set_pixels(&mut self, pixels: &[f16], region: &Region) -> Result<()> {
-        let region = match region {
-            Region::All => match self.data_window() {
-                Region::All => {
-                    // If this image buffer is uninitialized, we can't
-                    // get here because
-                    // `self.storage()` will return
-                    // `ImageBufferStorage::Uninitialized`.
-                    unreachable!()
-                }
-                Region::Bounds(roi) => roi,
-            },
-            Region::Bounds(roi) => roi.clone(),
-        };
-
-        let size = region.pixel_count() * region.channel_count() as usize;
-
-        if size > pixels.len() {
-            return Err(anyhow!("Pixel data is too small"));
-        }
-
-        let mut is_ok = std::mem::MaybeUninit::<bool>::uninit();
-
-        unsafe {
-            oiio_ImageBuf_set_pixels_u16(
-                self.ptr,
-                region.into(),
-                CspanU16::new(std::mem::transmute(pixels)).as_raw_ptr() as _,
-                &raw mut is_ok as _,
-            );
-
-            if is_ok.assume_init() {
-                Ok(())
-            } else {
-                Err(anyhow!(self.error(true).unwrap_or(
-                    "ImageBuffer::set_pixels(): unknown error".into()
-                )))
-            }
-        }
-    }
-}
+pixels!(f16, CspanF16, BaseType::F16, oiio_ImageBuf_set_pixels_f16);
+pixels!(f32, CspanF32, BaseType::F32, oiio_ImageBuf_set_pixels_f32);
+pixels!(f64, CspanF64, BaseType::F64, oiio_ImageBuf_set_pixels_f64);
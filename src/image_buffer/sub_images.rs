@@ -0,0 +1,192 @@
+use crate::*;
+use anyhow::{anyhow, Result};
+use core::mem::MaybeUninit;
+
+/// # Subimages
+///
+/// Some file formats can store several images in one file. E.g. the parts of
+/// a multi-part OpenEXR file or the pages of a multi-page TIFF.
+impl ImageBuffer {
+    /// Read all subimages of the named file.
+    ///
+    /// Each subimage is returned as its own `ImageBuffer`, in the order they
+    /// are stored in the file. The name of a subimage (e.g. the part name of
+    /// a multi-part OpenEXR) is available via
+    /// [`sub_image_name()`](ImageBuffer::sub_image_name).
+    ///
+    /// Just like [`from_file()`](ImageBuffer::from_file), pixels are read
+    /// lazily.
+    #[named]
+    pub fn read_all_subimages(name: &Utf8Path) -> Result<Vec<ImageBuffer>> {
+        let first = Self::from_file_subimage(name, 0)?;
+        let sub_image_count = first.sub_image_count();
+
+        let mut image_buffers = vec![first];
+
+        for sub_image in 1.. {
+            if sub_image_count.is_some_and(|count| count <= sub_image) {
+                break;
+            }
+
+            match Self::from_file_subimage(name, sub_image) {
+                Ok(image_buffer) => image_buffers.push(image_buffer),
+                // The count is unknown. We ran past the last subimage.
+                Err(_) if sub_image_count.is_none() => break,
+                Err(error) => {
+                    return Err(anyhow!(
+                        "[fn {}] Subimage {sub_image}: {error}",
+                        function_name!()
+                    ))
+                }
+            }
+        }
+
+        Ok(image_buffers)
+    }
+
+    /// Write all `image_buffers` as subimages of a single file.
+    ///
+    /// Each `ImageBuffer` becomes one part of a multi-part OpenEXR or one page
+    /// of a multi-page TIFF. Set the name of an OpenEXR part with
+    /// [`set_sub_image_name()`](ImageBuffer::set_sub_image_name) before
+    /// writing.
+    ///
+    /// Returns an error if the file format can not store multiple subimages.
+    #[named]
+    pub fn write_multi(file_name: &Utf8Path, image_buffers: &[&ImageBuffer]) -> Result<()> {
+        if image_buffers.is_empty() {
+            return Err(anyhow!("[fn {}] No images to write", function_name!()));
+        }
+
+        let output = ImageOutput::create(file_name)
+            .ok_or_else(|| anyhow!("[fn {}] {}", function_name!(), global_error()))?;
+
+        let output_error = || anyhow!("[fn {}] {}", function_name!(), output.error());
+
+        if 1 < image_buffers.len() && !output.supports(c"multiimage") {
+            return Err(anyhow!(
+                "[fn {}] The format of '{file_name}' does not support multiple subimages",
+                function_name!()
+            ));
+        }
+
+        let mut image_specs = VecImageSpec::new();
+        image_buffers
+            .iter()
+            .for_each(|image_buffer| image_specs.push(image_buffer.image_spec_ptr()));
+
+        if !output.open_multi_subimage(&image_specs) {
+            return Err(output_error());
+        }
+
+        for (index, image_buffer) in image_buffers.iter().enumerate() {
+            if 0 < index
+                && !output.open(
+                    image_buffer.image_spec_ptr(),
+                    oiio_OpenMode::oiio_OpenMode_AppendSubimage,
+                )
+            {
+                return Err(output_error());
+            }
+
+            if !output.write_image_buffer(image_buffer) {
+                return Err(match image_buffer.error(true) {
+                    Some(error) => anyhow!("[fn {}] {error}", function_name!()),
+                    None => output_error(),
+                });
+            }
+        }
+
+        if !output.close() {
+            return Err(output_error());
+        }
+
+        Ok(())
+    }
+
+    /// Return the name of the subimage, e.g. the part name of a multi-part
+    /// OpenEXR file.
+    ///
+    /// This is the value of the `oiio:subimagename` attribute of the
+    /// [`ImageSpec`]. Returns `None` if it is not set.
+    pub fn sub_image_name(&self) -> Option<String> {
+        self.string_attribute(c"oiio:subimagename")
+    }
+
+    /// Set the name this image is given when written as a subimage with
+    /// [`write_multi()`](ImageBuffer::write_multi).
+    pub fn set_sub_image_name(&mut self, name: &str) {
+        self.set_string_attribute(c"oiio:subimagename", name);
+    }
+}
+
+impl ImageBuffer {
+    #[named]
+    fn from_file_subimage(name: &Utf8Path, sub_image: u32) -> Result<Self> {
+        let image_buffer = Self::from_file_with(
+            name,
+            &FromFileOptions {
+                sub_image,
+                ..Default::default()
+            },
+        )?;
+
+        let mut is_ok = MaybeUninit::<bool>::uninit();
+
+        // Reads the header so a missing subimage is reported here.
+        unsafe {
+            oiio_ImageBuf_init_spec(
+                image_buffer.ptr,
+                StringView::from(name).as_raw_ptr() as _,
+                sub_image as _,
+                0,
+                &raw mut is_ok as _,
+            );
+
+            image_buffer.self_or_error(is_ok.assume_init(), function_name!())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn sub_images() -> Result<()> {
+        let mut beauty = ImageBuffer::from_fill(
+            &[0.5, 0.5, 0.5],
+            &Bounds::new(0..16, 0..8, 0..1, Some(0..3)),
+        )?;
+        beauty.set_sub_image_name("beauty");
+
+        let mut depth =
+            ImageBuffer::from_fill(&[2.0], &Bounds::new(0..16, 0..8, 0..1, Some(0..1)))?;
+        depth.set_sub_image_name("depth");
+
+        let file_name = Utf8Path::new("target/sub_images.exr");
+        ImageBuffer::write_multi(file_name, &[&beauty, &depth])?;
+
+        let image_buffers = ImageBuffer::read_all_subimages(file_name)?;
+
+        assert_eq!(2, image_buffers.len());
+        assert_eq!(
+            vec![Some("beauty".to_string()), Some("depth".to_string())],
+            image_buffers
+                .iter()
+                .map(ImageBuffer::sub_image_name)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(1, image_buffers[1].channel_count());
+        assert_eq!(1, image_buffers[1].sub_image());
+
+        // PNG can only store a single image.
+        assert!(ImageBuffer::write_multi(
+            Utf8Path::new("target/sub_images.png"),
+            &[&beauty, &depth]
+        )
+        .is_err());
+
+        Ok(())
+    }
+}
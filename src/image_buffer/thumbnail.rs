@@ -0,0 +1,199 @@
+use crate::*;
+use core::mem::MaybeUninit;
+
+/// # Thumbnails
+///
+/// Some file formats (e.g. JPEG, TIFF or OpenEXR) can store a small preview
+/// of the image alongside the pixels. Reading it does not require decoding the
+/// full image.
+///
+/// A thumbnail that is set on an `ImageBuffer` is stored in the file when the
+/// image is [written](ImageBuffer::write), if the file format supports it.
+impl ImageBuffer {
+    /// Returns `true` if this image has a thumbnail.
+    pub fn has_thumbnail(&self) -> bool {
+        let mut has_thumbnail = MaybeUninit::<bool>::uninit();
+
+        unsafe {
+            oiio_ImageBuf_has_thumbnail(self.ptr, &raw mut has_thumbnail as _);
+
+            has_thumbnail.assume_init()
+        }
+    }
+
+    /// Returns a copy of the thumbnail of this image or `None` if it has none.
+    ///
+    /// For an image read from a file this is the thumbnail stored in the file.
+    /// Use [`ImageCache::thumbnail()`] to get it without opening the file as
+    /// an `ImageBuffer`.
+    pub fn thumbnail(&self) -> Option<ImageBuffer> {
+        if !self.has_thumbnail() {
+            return None;
+        }
+
+        let mut thumbnail_ptr = MaybeUninit::<*mut oiio_ImageBufSharedPtr_t>::uninit();
+        let mut ptr = MaybeUninit::<*mut oiio_ImageBuf_t>::uninit();
+
+        let thumbnail = unsafe {
+            oiio_ImageBuf_get_thumbnail(self.ptr, &raw mut thumbnail_ptr as _);
+            let thumbnail_ptr = thumbnail_ptr.assume_init();
+
+            // Copy the pixels so we do not share them with `self`.
+            oiio_ImageBufSharedPtr_copy_01(
+                thumbnail_ptr,
+                oiio_TypeDesc_t::default(),
+                &raw mut ptr as _,
+            );
+            oiio_ImageBufSharedPtr_dtor(thumbnail_ptr);
+
+            ImageBuffer::from_raw_ptr(ptr.assume_init())
+        };
+
+        thumbnail.is_initialized().then_some(thumbnail)
+    }
+
+    /// Set the thumbnail of this image to a copy of `thumbnail`.
+    pub fn set_thumbnail(&mut self, thumbnail: &ImageBuffer) -> &mut Self {
+        unsafe { oiio_ImageBuf_set_thumbnail(self.ptr, thumbnail.ptr) };
+
+        self
+    }
+
+    /// Remove the thumbnail of this image.
+    pub fn clear_thumbnail(&mut self) -> &mut Self {
+        unsafe { oiio_ImageBuf_clear_thumbnail(self.ptr) };
+
+        self
+    }
+}
+
+/// # Thumbnail Generation
+#[cfg(feature = "algorithms")]
+impl ImageBuffer {
+    /// Create a thumbnail that fits into `max_size`×`max_size` pixels,
+    /// keeping the aspect ratio of the image.
+    ///
+    /// If this image was read from a MIP-mapped file, the thumbnail is
+    /// resized from the smallest MIP-level that is still at least `max_size`
+    /// large. Only that level is read.
+    ///
+    /// An image that already fits is copied.
+    ///
+    /// This ignores any existing thumbnail. To show the stored thumbnail if
+    /// there is one and only generate one for files without, use:
+    ///
+    /// ```ignore
+    /// let thumbnail = image_buffer
+    ///     .thumbnail()
+    ///     .map_or_else(|| image_buffer.generate_thumbnail(256), Ok)?;
+    /// ```
+    pub fn generate_thumbnail(&self, max_size: u32) -> Result<ImageBuffer> {
+        let max_size = max_size.max(1);
+        let fits = |image_buffer: &ImageBuffer| {
+            let data_window = image_buffer.data_window();
+
+            data_window.width().max(data_window.height()) <= max_size
+        };
+
+        if fits(self) {
+            return Ok(self.clone());
+        }
+
+        // The levels shrink, so the last one that does not fit is the
+        // smallest source we can use.
+        let mut source = None;
+        for mip_level in self.mip_levels().skip(1) {
+            let mip_level = mip_level?;
+
+            if fits(&mip_level) {
+                break;
+            }
+            source = Some(mip_level);
+        }
+        let source = source.as_ref().unwrap_or(self);
+
+        let data_window = source.data_window();
+        let scale = max_size as f32 / data_window.width().max(data_window.height()) as f32;
+        let width = ((data_window.width() as f32 * scale).round() as u32).clamp(1, max_size);
+        let height = ((data_window.height() as f32 * scale).round() as u32).clamp(1, max_size);
+
+        let mut thumbnail = ImageBuffer::new();
+        thumbnail.replace_by_resize_with(
+            source,
+            &algorithms::ResizeOptions {
+                region: Bounds::new(
+                    0..width as _,
+                    0..height as _,
+                    0..1,
+                    Some(0..source.channel_count()),
+                )
+                .into(),
+                ..Default::default()
+            },
+        )?;
+
+        Ok(thumbnail)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn thumbnail() -> Result<()> {
+        let mut image_buffer = ImageBuffer::from_fill(
+            &[0.25, 0.5, 1.0],
+            &Bounds::new(0..512, 0..256, 0..1, Some(0..3)),
+        )?;
+        assert!(!image_buffer.has_thumbnail());
+        assert!(image_buffer.thumbnail().is_none());
+
+        let thumbnail = image_buffer.generate_thumbnail(64)?;
+        assert_eq!(64, thumbnail.data_window().width());
+        assert_eq!(32, thumbnail.data_window().height());
+        assert_eq!(3, thumbnail.channel_count());
+
+        image_buffer.set_thumbnail(&thumbnail);
+        assert!(image_buffer.has_thumbnail());
+        assert_eq!(
+            Some(32),
+            image_buffer
+                .thumbnail()
+                .map(|thumbnail| thumbnail.data_window().height())
+        );
+
+        // TIFF stores the thumbnail alongside the pixels.
+        let file_name = Utf8Path::new("target/thumbnail.tif");
+        image_buffer.write(file_name)?;
+
+        let size = |thumbnail: ImageBuffer| {
+            let data_window = thumbnail.data_window();
+
+            (data_window.width(), data_window.height())
+        };
+        assert_eq!(
+            Some((64, 32)),
+            ImageBuffer::from_file(file_name)?.thumbnail().map(size)
+        );
+        assert_eq!(
+            Some((64, 32)),
+            ImageCache::new().thumbnail(file_name).map(size)
+        );
+
+        image_buffer.clear_thumbnail();
+        assert!(!image_buffer.has_thumbnail());
+
+        // Already small enough.
+        assert_eq!(
+            512,
+            image_buffer.generate_thumbnail(1024)?.data_window().width()
+        );
+
+        assert!(ImageCache::new()
+            .thumbnail(Utf8Path::new("target/does_not_exist.exr"))
+            .is_none());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,436 @@
+use crate::*;
+use anyhow::{anyhow, Result};
+use core::{
+    marker::PhantomData,
+    mem::MaybeUninit,
+    ops::{Index, IndexMut},
+    ptr::NonNull,
+    slice,
+};
+
+/// A pixel with a fixed number of channels of a [`PixelData`] type.
+///
+/// # Safety
+///
+/// Implementors must have the exact memory layout of
+/// `[Self::Channel; Self::CHANNEL_COUNT]`.
+pub unsafe trait PixelFormat: Copy + Default + Send + Sync + 'static {
+    type Channel: PixelData;
+    const CHANNEL_COUNT: u32;
+
+    fn channels(&self) -> &[Self::Channel];
+    fn channels_mut(&mut self) -> &mut [Self::Channel];
+}
+
+macro_rules! pixel_format {
+    ($(#[$attribute:meta])* $name:ident, $channel_count:literal) => {
+        $(#[$attribute])*
+        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+        #[repr(transparent)]
+        pub struct $name<T>(pub [T; $channel_count]);
+
+        unsafe impl<T: PixelData> PixelFormat for $name<T> {
+            type Channel = T;
+            const CHANNEL_COUNT: u32 = $channel_count;
+
+            #[inline]
+            fn channels(&self) -> &[T] {
+                &self.0
+            }
+
+            #[inline]
+            fn channels_mut(&mut self) -> &mut [T] {
+                &mut self.0
+            }
+        }
+
+        impl<T> From<[T; $channel_count]> for $name<T> {
+            #[inline]
+            fn from(channels: [T; $channel_count]) -> Self {
+                Self(channels)
+            }
+        }
+
+        impl<T> From<$name<T>> for [T; $channel_count] {
+            #[inline]
+            fn from(pixel: $name<T>) -> Self {
+                pixel.0
+            }
+        }
+    };
+}
+
+pixel_format!(
+    /// A single channel (gray) pixel.
+    Luma,
+    1
+);
+pixel_format!(
+    /// A gray pixel with alpha.
+    LumaA,
+    2
+);
+pixel_format!(
+    /// An RGB pixel.
+    Rgb,
+    3
+);
+pixel_format!(
+    /// An RGB pixel with alpha.
+    Rgba,
+    4
+);
+
+/// A 2D image whose pixel format is known at compile time.
+///
+/// This wraps an [`ImageBuffer`] with
+/// [`LocalBuffer`](ImageBufferStorage::LocalBuffer) storage. Channel count and
+/// [`BaseType`] are checked once, when the `TypedImage` is created. After that
+/// all pixel access is infallible and goes straight to memory.
+///
+/// Pixel coordinates are relative to the origin of the
+/// [`data_window()`](ImageBuffer::data_window), i.e. `(0, 0)` is always the
+/// top left pixel. Accessing a pixel outside the image panics.
+///
+/// Use [`as_image_buffer()`](TypedImage::as_image_buffer) or convert into an
+/// `ImageBuffer` to run [`algorithms`](module@algorithms) on it.
+///
+/// # Examples
+///
+/// ```ignore
+/// let mut image = TypedImage::<Rgba<f32>>::new(64, 32);
+///
+/// image[(1, 2)] = Rgba([1.0, 0.5, 0.0, 1.0]);
+///
+/// for row in image.rows_mut() {
+///     row[0] = Rgba([0.0, 0.0, 0.0, 1.0]);
+/// }
+///
+/// let image_buffer = ImageBuffer::from(image);
+/// ```
+#[derive(Debug)]
+pub struct TypedImage<P: PixelFormat> {
+    image_buffer: ImageBuffer,
+    pixels: NonNull<P>,
+    width: u32,
+    height: u32,
+    // In pixels.
+    row_stride: usize,
+    _marker: PhantomData<P>,
+}
+
+unsafe impl<P: PixelFormat> Send for TypedImage<P> {}
+unsafe impl<P: PixelFormat> Sync for TypedImage<P> {}
+
+impl<P: PixelFormat> TypedImage<P> {
+    /// Create an image of the given size with all pixels set to zero.
+    pub fn new(width: u32, height: u32) -> Self {
+        let image_buffer = ImageBuffer::from_dimensions_ffi(
+            width,
+            height,
+            P::CHANNEL_COUNT as _,
+            TypeDesc {
+                base_type: Some(<P::Channel as PixelData>::BASE_TYPE),
+                ..Default::default()
+            },
+            None,
+        );
+
+        Self::from_local_buffer(image_buffer)
+    }
+
+    /// Create an image of the given size with pixels computed by `f(x, y)`.
+    pub fn from_fn(width: u32, height: u32, mut f: impl FnMut(u32, u32) -> P) -> Self {
+        let mut image = Self::new(width, height);
+
+        image.rows_mut().enumerate().for_each(|(y, row)| {
+            row.iter_mut()
+                .enumerate()
+                .for_each(|(x, pixel)| *pixel = f(x as _, y as _))
+        });
+
+        image
+    }
+
+    #[inline]
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    #[inline]
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    #[inline]
+    pub fn dimensions(&self) -> (u32, u32) {
+        (self.width, self.height)
+    }
+
+    /// Returns the pixel at `(x, y)`.
+    #[inline]
+    pub fn get(&self, x: u32, y: u32) -> P {
+        self[(x, y)]
+    }
+
+    /// Returns a mutable reference to the pixel at `(x, y)`.
+    #[inline]
+    pub fn get_mut(&mut self, x: u32, y: u32) -> &mut P {
+        &mut self[(x, y)]
+    }
+
+    /// Sets the pixel at `(x, y)`.
+    #[inline]
+    pub fn put(&mut self, x: u32, y: u32, pixel: P) {
+        self[(x, y)] = pixel;
+    }
+
+    /// Returns row `y` of the image.
+    #[inline]
+    pub fn row(&self, y: u32) -> &[P] {
+        assert!(y < self.height, "Row {y} is outside the image");
+
+        unsafe { self.row_unchecked(y as _) }
+    }
+
+    /// Returns row `y` of the image.
+    #[inline]
+    pub fn row_mut(&mut self, y: u32) -> &mut [P] {
+        assert!(y < self.height, "Row {y} is outside the image");
+
+        unsafe { self.row_unchecked_mut(y as _) }
+    }
+
+    /// Iterates over the rows of the image, top to bottom.
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[P]> + ExactSizeIterator {
+        (0..self.height as usize).map(|y| unsafe { self.row_unchecked(y) })
+    }
+
+    /// Iterates over the rows of the image, top to bottom.
+    pub fn rows_mut(&mut self) -> impl DoubleEndedIterator<Item = &mut [P]> + ExactSizeIterator {
+        let (pixels, width, row_stride) = (self.pixels, self.width as usize, self.row_stride);
+
+        // Rows never overlap as `row_stride` is at least `width`.
+        (0..self.height as usize).map(move |y| unsafe {
+            slice::from_raw_parts_mut(pixels.as_ptr().add(y * row_stride), width)
+        })
+    }
+
+    /// Returns the underlying `ImageBuffer`.
+    #[inline]
+    pub fn as_image_buffer(&self) -> &ImageBuffer {
+        &self.image_buffer
+    }
+
+    /// Consumes the `TypedImage`, returning the underlying `ImageBuffer`.
+    #[inline]
+    pub fn into_image_buffer(self) -> ImageBuffer {
+        self.image_buffer
+    }
+}
+
+impl<P: PixelFormat> TypedImage<P> {
+    #[inline]
+    unsafe fn row_unchecked(&self, y: usize) -> &[P] {
+        slice::from_raw_parts(
+            self.pixels.as_ptr().add(y * self.row_stride),
+            self.width as _,
+        )
+    }
+
+    #[inline]
+    unsafe fn row_unchecked_mut(&mut self, y: usize) -> &mut [P] {
+        slice::from_raw_parts_mut(
+            self.pixels.as_ptr().add(y * self.row_stride),
+            self.width as _,
+        )
+    }
+
+    #[named]
+    fn check(image_buffer: &ImageBuffer) -> Result<()> {
+        if P::CHANNEL_COUNT != image_buffer.channel_count() {
+            return Err(anyhow!(
+                "[fn {}] Expected {} channels, image has {}",
+                function_name!(),
+                P::CHANNEL_COUNT,
+                image_buffer.channel_count()
+            ));
+        }
+
+        let base_type = <P::Channel as PixelData>::BASE_TYPE;
+
+        if Some(base_type) != image_buffer.type_desc().base_type {
+            return Err(anyhow!(
+                "[fn {}] Pixels are not stored as {:?}",
+                function_name!(),
+                base_type
+            ));
+        }
+
+        if 1 != image_buffer.data_window().depth() {
+            return Err(anyhow!("[fn {}] Image is not 2D", function_name!()));
+        }
+
+        Ok(())
+    }
+
+    // The caller must ensure the pixel format matches and the storage is local.
+    fn from_local_buffer(mut image_buffer: ImageBuffer) -> Self {
+        let data_window = image_buffer.data_window();
+
+        let mut pixels = MaybeUninit::<*mut core::ffi::c_void>::uninit();
+        let mut row_stride = MaybeUninit::<i64>::uninit();
+
+        let (pixels, row_stride) = unsafe {
+            oiio_ImageBuf_localpixels_00(image_buffer.ptr, &raw mut pixels as _);
+            oiio_ImageBuf_scanline_stride(image_buffer.ptr, &raw mut row_stride as _);
+
+            (pixels.assume_init(), row_stride.assume_init())
+        };
+
+        Self {
+            pixels: NonNull::new(pixels as *mut P).unwrap_or(NonNull::dangling()),
+            width: data_window.width(),
+            height: data_window.height(),
+            row_stride: row_stride as usize / size_of::<P>(),
+            image_buffer,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<P: PixelFormat> Clone for TypedImage<P> {
+    fn clone(&self) -> Self {
+        Self::from_local_buffer(self.image_buffer.clone())
+    }
+}
+
+impl<P: PixelFormat> Index<(u32, u32)> for TypedImage<P> {
+    type Output = P;
+
+    #[inline]
+    fn index(&self, (x, y): (u32, u32)) -> &P {
+        assert!(
+            x < self.width && y < self.height,
+            "Pixel ({x}, {y}) is outside the image"
+        );
+
+        unsafe {
+            &*self
+                .pixels
+                .as_ptr()
+                .add(y as usize * self.row_stride + x as usize)
+        }
+    }
+}
+
+impl<P: PixelFormat> IndexMut<(u32, u32)> for TypedImage<P> {
+    #[inline]
+    fn index_mut(&mut self, (x, y): (u32, u32)) -> &mut P {
+        assert!(
+            x < self.width && y < self.height,
+            "Pixel ({x}, {y}) is outside the image"
+        );
+
+        unsafe {
+            &mut *self
+                .pixels
+                .as_ptr()
+                .add(y as usize * self.row_stride + x as usize)
+        }
+    }
+}
+
+impl<P: PixelFormat> AsRef<ImageBuffer> for TypedImage<P> {
+    #[inline]
+    fn as_ref(&self) -> &ImageBuffer {
+        &self.image_buffer
+    }
+}
+
+impl<P: PixelFormat> From<TypedImage<P>> for ImageBuffer {
+    #[inline]
+    fn from(image: TypedImage<P>) -> Self {
+        image.image_buffer
+    }
+}
+
+/// Fails if the channel count or [`BaseType`] of the `ImageBuffer` do not match
+/// `P`.
+///
+/// Images that are not held in a local buffer (e.g. backed by an
+/// [`ImageCache`]) are copied into one.
+impl<P: PixelFormat> TryFrom<ImageBuffer> for TypedImage<P> {
+    type Error = anyhow::Error;
+
+    fn try_from(image_buffer: ImageBuffer) -> Result<Self> {
+        let image_buffer = match image_buffer.storage() {
+            ImageBufferStorage::LocalBuffer => image_buffer,
+            _ => image_buffer.copy(&TypeDesc::default())?,
+        };
+
+        Self::check(&image_buffer)?;
+
+        Ok(Self::from_local_buffer(image_buffer))
+    }
+}
+
+/// Copies the pixels, converting them to the [`BaseType`] of `P`.
+///
+/// Fails if the channel count of the `ImageBuffer` does not match `P`.
+impl<P: PixelFormat> TryFrom<&ImageBuffer> for TypedImage<P> {
+    type Error = anyhow::Error;
+
+    fn try_from(image_buffer: &ImageBuffer) -> Result<Self> {
+        image_buffer
+            .copy(&TypeDesc {
+                base_type: Some(<P::Channel as PixelData>::BASE_TYPE),
+                ..Default::default()
+            })?
+            .try_into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn typed_image() -> Result<()> {
+        let mut image =
+            TypedImage::<Rgba<f32>>::from_fn(4, 2, |x, y| Rgba([x as _, y as _, 0.0, 1.0]));
+
+        assert_eq!(Rgba([3.0, 1.0, 0.0, 1.0]), image[(3, 1)]);
+
+        image.put(0, 1, Rgba([0.5; 4]));
+        image.get_mut(1, 1).0[2] = 2.0;
+
+        assert_eq!(2, image.rows().len());
+        assert_eq!(
+            [0.5, 1.0, 2.0],
+            [
+                image.row(1)[0].0[0],
+                image.get(1, 1).0[1],
+                image[(1, 1)].0[2]
+            ]
+        );
+
+        let image_buffer = ImageBuffer::from(image.clone());
+        let pixels: Vec<f32> = image_buffer.pixels(&Region::All)?;
+        assert_eq!(&[0.5; 4], &pixels[16..20]);
+
+        // Wrong channel count.
+        assert!(TypedImage::<Rgb<f32>>::try_from(image_buffer.clone()).is_err());
+        // Wrong base type.
+        assert!(TypedImage::<Rgba<u16>>::try_from(image_buffer.clone()).is_err());
+
+        // Converting.
+        let image = TypedImage::<Rgba<u16>>::try_from(&image_buffer)?;
+        assert_eq!(Rgba([u16::MAX / 2 + 1; 4]), image[(0, 1)]);
+
+        let image = TypedImage::<Rgba<f32>>::try_from(image_buffer)?;
+        assert_eq!(Rgba([0.5; 4]), image[(0, 1)]);
+
+        Ok(())
+    }
+}
@@ -149,6 +149,34 @@ impl ImageCache {
     }
 }
 
+/// # Thumbnails
+impl ImageCache {
+    /// Returns the thumbnail stored in the named file.
+    ///
+    /// This only reads the file's header and thumbnail, not its pixels.
+    ///
+    /// Returns `None` if the file has no thumbnail or can not be opened.
+    pub fn thumbnail(&self, name: &Utf8Path) -> Option<ImageBuffer> {
+        let name = Ustring::from(name.as_str());
+        let mut thumbnail = ImageBuffer::new();
+        let mut is_ok = MaybeUninit::<bool>::uninit();
+
+        let is_ok = unsafe {
+            oiio_ImageCacheSharedPtr_get_thumbnail(
+                self.as_raw_ptr_mut(),
+                name.as_raw_ptr() as _,
+                thumbnail.as_raw_ptr_mut(),
+                0,
+                &raw mut is_ok as _,
+            );
+
+            is_ok.assume_init()
+        };
+
+        (is_ok && thumbnail.is_initialized()).then_some(thumbnail)
+    }
+}
+
 impl ImageCache {
     pub(crate) fn _as_raw_ptr(&self) -> *const oiio_ImageCacheSharedPtr_t {
         *self.ptr
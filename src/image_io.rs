@@ -1,9 +1,230 @@
+use crate::*;
+use core::{
+    ffi::{c_int, CStr},
+    mem::MaybeUninit,
+    ptr,
+};
+use std::ffi::CString;
+
 /// Handles image input.
 ///
 /// This is a placeholder for now.
 pub struct ImageInput {}
 
-/// Handles image output.s
+/// Handles image output.
 ///
-/// This is a placeholder for now.
-pub struct ImageOutput {}
+/// Owns an `ImageOutput::unique_ptr`. This is only used internally for now,
+/// e.g. by [`ImageBuffer::write_multi()`].
+pub struct ImageOutput {
+    ptr: *mut oiio_ImageOutputPtr_t,
+    output: *mut oiio_ImageOutput_t,
+    file_name: CString,
+}
+
+impl ImageOutput {
+    /// Create an `ImageOutput` for the file format implied by the extension
+    /// of `file_name`.
+    ///
+    /// Returns `None` if there is no such format. Use [`global_error()`] to
+    /// find out why.
+    pub(crate) fn create(file_name: &Utf8Path) -> Option<Self> {
+        let file_name = CString::new(file_name.as_str()).ok()?;
+
+        let mut ptr = MaybeUninit::<*mut oiio_ImageOutputPtr_t>::uninit();
+        let mut output = MaybeUninit::<*mut oiio_ImageOutput_t>::uninit();
+
+        let (ptr, output) = unsafe {
+            oiio_ImageOutput_create(
+                file_name.as_ptr(),
+                ptr::null_mut(),
+                c"".as_ptr(),
+                &raw mut ptr as _,
+            );
+
+            let ptr = ptr.assume_init();
+            oiio_ImageOutputPtr_get(ptr, &raw mut output as _);
+
+            (ptr, output.assume_init())
+        };
+
+        let output = Self {
+            ptr,
+            output,
+            file_name,
+        };
+
+        // Dropping `output` frees the empty `unique_ptr`.
+        (!output.output.is_null()).then_some(output)
+    }
+
+    /// Returns `true` if the file format supports `feature`, e.g. `"tiles"`,
+    /// `"mipmap"` or `"multiimage"`.
+    pub(crate) fn supports(&self, feature: &CStr) -> bool {
+        let mut supports = MaybeUninit::<c_int>::uninit();
+
+        unsafe {
+            oiio_ImageOutput_supports(self.output, feature.as_ptr(), &raw mut supports as _);
+
+            0 != supports.assume_init()
+        }
+    }
+
+    /// Open the file for writing `image_spec`, or append it as a new subimage
+    /// or MIP-level, depending on `open_mode`.
+    pub(crate) fn open(
+        &self,
+        image_spec: *const oiio_ImageSpec_t,
+        open_mode: oiio_OpenMode,
+    ) -> bool {
+        let mut is_ok = MaybeUninit::<bool>::uninit();
+
+        unsafe {
+            oiio_ImageOutput_open(
+                self.output,
+                self.file_name.as_ptr(),
+                image_spec,
+                open_mode,
+                &raw mut is_ok as _,
+            );
+
+            is_ok.assume_init()
+        }
+    }
+
+    /// Open the file for writing one subimage per element of `image_specs`.
+    pub(crate) fn open_multi_subimage(&self, image_specs: &VecImageSpec) -> bool {
+        let mut is_ok = MaybeUninit::<bool>::uninit();
+
+        unsafe {
+            oiio_ImageOutput_open_multi_subimage(
+                self.output,
+                self.file_name.as_ptr(),
+                image_specs.len() as _,
+                image_specs.data(),
+                &raw mut is_ok as _,
+            );
+
+            is_ok.assume_init()
+        }
+    }
+
+    /// Write the pixels of `image_buffer` to the current subimage or
+    /// MIP-level.
+    ///
+    /// On failure, check [`ImageBuffer::error()`] before
+    /// [`error()`](ImageOutput::error).
+    pub(crate) fn write_image_buffer(&self, image_buffer: &ImageBuffer) -> bool {
+        let mut is_ok = MaybeUninit::<bool>::uninit();
+
+        unsafe {
+            oiio_ImageBuf_write_to_output(
+                image_buffer.as_raw_ptr(),
+                self.output,
+                &raw mut is_ok as _,
+            );
+
+            is_ok.assume_init()
+        }
+    }
+
+    pub(crate) fn close(&self) -> bool {
+        let mut is_ok = MaybeUninit::<bool>::uninit();
+
+        unsafe {
+            oiio_ImageOutput_close(self.output, &raw mut is_ok as _);
+
+            is_ok.assume_init()
+        }
+    }
+
+    /// Returns and clears the last error.
+    pub(crate) fn error(&self) -> String {
+        let mut error = MaybeUninit::<*mut oiio_String_t>::uninit();
+
+        unsafe {
+            oiio_ImageOutput_geterror(self.output, true, &raw mut error as _);
+
+            OiioString::from(error.assume_init()).to_string()
+        }
+    }
+}
+
+impl Drop for ImageOutput {
+    fn drop(&mut self) {
+        unsafe { oiio_ImageOutputPtr_dtor(self.ptr) };
+    }
+}
+
+/// Owns a `std::vector<ImageSpec>`.
+pub(crate) struct VecImageSpec {
+    ptr: *mut oiio_VecImageSpec_t,
+    len: usize,
+}
+
+impl VecImageSpec {
+    pub(crate) fn new() -> Self {
+        let mut ptr = MaybeUninit::<*mut oiio_VecImageSpec_t>::uninit();
+
+        unsafe {
+            oiio_VecImageSpec_default(&raw mut ptr as _);
+
+            Self {
+                ptr: ptr.assume_init(),
+                len: 0,
+            }
+        }
+    }
+
+    /// Appends a copy of `image_spec`.
+    pub(crate) fn push(&mut self, image_spec: *const oiio_ImageSpec_t) {
+        unsafe { oiio_VecImageSpec_push_back(self.ptr, image_spec) };
+
+        self.len += 1;
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.len
+    }
+
+    /// The `index`th `ImageSpec`, for modification.
+    pub(crate) fn get_mut(&mut self, index: usize) -> *mut oiio_ImageSpec_t {
+        assert!(index < self.len);
+
+        let mut image_spec = MaybeUninit::<*const oiio_ImageSpec_t>::uninit();
+
+        unsafe {
+            oiio_VecImageSpec_op_index(self.ptr, index, &raw mut image_spec as _);
+
+            // The elements are owned by the vector, which we own.
+            image_spec.assume_init() as _
+        }
+    }
+
+    pub(crate) fn data(&self) -> *const oiio_ImageSpec_t {
+        let mut data = MaybeUninit::<*const oiio_ImageSpec_t>::uninit();
+
+        unsafe {
+            oiio_VecImageSpec_data_const(self.ptr, &raw mut data as _);
+
+            data.assume_init()
+        }
+    }
+}
+
+impl Drop for VecImageSpec {
+    fn drop(&mut self) {
+        unsafe { oiio_VecImageSpec_dtor(self.ptr) };
+    }
+}
+
+/// Returns and clears the last error that is not tied to a specific
+/// `ImageOutput`, e.g. from [`ImageOutput::create()`].
+pub(crate) fn global_error() -> String {
+    let mut error = MaybeUninit::<*mut oiio_String_t>::uninit();
+
+    unsafe {
+        oiio_geterror(true, &raw mut error as _);
+
+        OiioString::from(error.assume_init()).to_string()
+    }
+}
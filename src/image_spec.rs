@@ -1,5 +1,8 @@
 use crate::*;
-use core::mem::MaybeUninit;
+use core::{
+    ffi::{c_char, c_longlong},
+    mem::MaybeUninit,
+};
 use std::ptr::slice_from_raw_parts;
 use ustr::Ustr;
 
@@ -252,16 +255,34 @@ impl From<ImageSpec> for ImageSpecInternal {
                     oiio_ImageSpec_set_nchannels(ptr, *len as _);
                 }
                 ChannelFormat::PerChannel(formats) => {
-                    oiio_ImageSpec_clear_and_reserve_channelformats(ptr, formats.len());
-                    for format in formats.iter() {
-                        oiio_ImageSpec_push_channelformat(
-                            ptr,
-                            oiio_TypeDesc_t {
-                                basetype: (*format).into(),
-                                ..Default::default()
-                            },
-                        );
-                    }
+                    let type_descs = formats
+                        .iter()
+                        .map(|&base_type| TypeDesc {
+                            base_type: Some(base_type),
+                            ..Default::default()
+                        })
+                        .collect::<Vec<_>>();
+
+                    // Like OIIO's readers do, we use the widest channel type as
+                    // the `format` of the image.
+                    let widest = type_descs
+                        .iter()
+                        .copied()
+                        .reduce(|widest, type_desc| {
+                            if widest.size() < type_desc.size() {
+                                type_desc
+                            } else {
+                                widest
+                            }
+                        })
+                        .unwrap_or_default();
+
+                    oiio_ImageSpec_set_format(ptr, widest.into());
+                    oiio_ImageSpec_set_nchannels(ptr, type_descs.len() as _);
+                    oiio_ImageSpec_set_channelformats(
+                        ptr,
+                        VecTypeDesc::from(type_descs.as_slice()).as_raw_ptr_mut(),
+                    );
                 }
             }
 
@@ -286,8 +307,14 @@ impl From<ImageSpec> for ImageSpecInternal {
 
 impl From<ImageSpecInternal> for ImageSpec {
     fn from(i: ImageSpecInternal) -> Self {
-        let ptr = i.ptr;
+        Self::from_raw_ptr(i.ptr)
+    }
+}
 
+impl ImageSpec {
+    /// Copies the contents of an `oiio_ImageSpec_t` that is owned elsewhere
+    /// (e.g. by an [`ImageBuffer`]).
+    pub(crate) fn from_raw_ptr(ptr: *const oiio_ImageSpec_t) -> Self {
         let mut r = Self::default();
 
         unsafe {
@@ -299,8 +326,8 @@ impl From<ImageSpecInternal> for ImageSpec {
             oiio_ImageSpec_get_height(ptr, &mut r.height as *mut _ as _);
             oiio_ImageSpec_get_depth(ptr, &mut r.depth as *mut _ as _);
             oiio_ImageSpec_get_full_x(ptr, &mut r.display_window_x as *mut _ as _);
-            oiio_ImageSpec_get_full_x(ptr, &mut r.display_window_y as *mut _ as _);
-            oiio_ImageSpec_get_full_x(ptr, &mut r.display_window_z as *mut _ as _);
+            oiio_ImageSpec_get_full_y(ptr, &mut r.display_window_y as *mut _ as _);
+            oiio_ImageSpec_get_full_z(ptr, &mut r.display_window_z as *mut _ as _);
             oiio_ImageSpec_get_full_width(ptr, &mut r.display_window_width as *mut _ as _);
             oiio_ImageSpec_get_full_height(ptr, &mut r.display_window_height as *mut _ as _);
             oiio_ImageSpec_get_full_depth(ptr, &mut r.display_window_depth as *mut _ as _);
@@ -308,15 +335,13 @@ impl From<ImageSpecInternal> for ImageSpec {
             oiio_ImageSpec_get_tile_height(ptr, &mut r.tile_height as *mut _ as _);
             oiio_ImageSpec_get_tile_depth(ptr, &mut r.tile_depth as *mut _ as _);
 
-            let mut channel_formats = MaybeUninit::<*mut oiio_VecTypeDesc_t>::uninit();
-            oiio_ImageSpec_get_channelformats(ptr, &mut channel_formats as *mut _ as _);
-            let channel_formats = channel_formats.assume_init();
+            // This returns one format per channel, even if all channels share
+            // the same format.
+            let mut channel_formats = VecTypeDesc::new();
+            oiio_ImageSpec_get_channelformats(ptr, channel_formats.as_raw_ptr_mut());
+            let channel_formats = channel_formats.to_vec();
 
-            let mut len = MaybeUninit::<u32>::uninit();
-            oiio_VecTypeDesc_size(channel_formats, &mut len as *mut _ as _);
-            let len = len.assume_init() as _;
-
-            if 0 == len {
+            if channel_formats.windows(2).all(|pair| pair[0] == pair[1]) {
                 let mut basetype = MaybeUninit::<u8>::uninit();
                 oiio_ImageSpec_get_format_basetype(ptr, &mut basetype as *mut _ as _);
                 let basetype = basetype.assume_init();
@@ -327,24 +352,36 @@ impl From<ImageSpecInternal> for ImageSpec {
 
                 r.channel_format = ChannelFormat::Uniform(basetype.try_into().unwrap(), len);
             } else {
-                let mut data = MaybeUninit::<*mut *const oiio_TypeDesc_t>::uninit();
-                oiio_VecTypeDesc_data(channel_formats, &mut data as *mut _ as _);
-                let data = data.assume_init();
-
-                let channel_formats = &*slice_from_raw_parts(data, len);
-
                 r.channel_format = ChannelFormat::PerChannel(
                     channel_formats
                         .iter()
-                        .map(|&c| (*c).basetype.try_into().unwrap())
+                        .map(|type_desc| type_desc.base_type.unwrap_or_default())
                         .collect::<Vec<_>>(),
                 );
             }
 
-            // TODO: get channel names
+            r.channel_name = (0..r.channel_format.channel_count())
+                .map(|channel| {
+                    let mut name = MaybeUninit::<*const c_char>::uninit();
+                    let mut len = MaybeUninit::<c_longlong>::uninit();
+                    oiio_ImageSpec_channel_name(
+                        ptr,
+                        channel as _,
+                        &raw mut name as _,
+                        &raw mut len as _,
+                    );
+
+                    let name = &*slice_from_raw_parts(
+                        name.assume_init() as *const u8,
+                        len.assume_init() as _,
+                    );
+
+                    ustr(&String::from_utf8_lossy(name))
+                })
+                .collect();
 
-            let alpha_channel_index = MaybeUninit::<i32>::uninit();
-            oiio_ImageSpec_get_alpha_channel(ptr, &mut r.alpha_channel_index as *mut _ as _);
+            let mut alpha_channel_index = MaybeUninit::<i32>::uninit();
+            oiio_ImageSpec_get_alpha_channel(ptr, &raw mut alpha_channel_index as _);
             let alpha_channel_index = alpha_channel_index.assume_init();
             if alpha_channel_index < 0 {
                 r.alpha_channel_index = None;
@@ -352,8 +389,8 @@ impl From<ImageSpecInternal> for ImageSpec {
                 r.alpha_channel_index = Some(alpha_channel_index as _);
             }
 
-            let z_channel_index = MaybeUninit::<i32>::uninit();
-            oiio_ImageSpec_get_z_channel(ptr, &mut r.z_channel_index as *mut _ as _);
+            let mut z_channel_index = MaybeUninit::<i32>::uninit();
+            oiio_ImageSpec_get_z_channel(ptr, &raw mut z_channel_index as _);
             let z_channel_index = z_channel_index.assume_init();
             if z_channel_index < 0 {
                 r.z_channel_index = None;
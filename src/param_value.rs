@@ -1,4 +1,6 @@
 use crate::*;
+#[cfg(feature = "half")]
+use half::f16;
 use std::marker::PhantomData;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -175,6 +177,8 @@ value_type_desc!(u8, BaseType::U8, Aggregate::Scalar);
 value_type_desc!(u16, BaseType::U16, Aggregate::Scalar);
 value_type_desc!(u32, BaseType::U32, Aggregate::Scalar);
 value_type_desc!(u64, BaseType::U64, Aggregate::Scalar);
+#[cfg(feature = "half")]
+value_type_desc!(f16, BaseType::F16, Aggregate::Scalar);
 value_type_desc!(f32, BaseType::F32, Aggregate::Scalar);
 value_type_desc!(f64, BaseType::F64, Aggregate::Scalar);
 value_type_desc!(i8, BaseType::I8, Aggregate::Scalar);
@@ -491,6 +495,10 @@ mod tests {
 
         // single String
         let _baz = ParamValue::new("baz", "forty two");
+
+        // single `f16`
+        #[cfg(feature = "half")]
+        let _qux = ParamValue::new("qux", f16::from_f32(42.0));
     }
 
     #[test]
@@ -255,6 +255,38 @@ impl TextureHandle<'_, '_> {
         }
     }
 
+    /// Like [`texture()`](TextureHandle::texture) but returns `f16` values.
+    ///
+    /// The lookup itself is always filtered in `f32`; only the result is
+    /// narrowed.
+    #[cfg(feature = "half")]
+    pub fn texture_f16(
+        &self,
+        s: f32,
+        t: f32,
+        delta_s_dx: f32,
+        delta_t_dx: f32,
+        delta_s_dy: f32,
+        delta_t_dy: f32,
+        channel_count: u16,
+        options: Option<&TextureOptions>,
+    ) -> Result<Vec<half::f16>> {
+        Ok(self
+            .texture(
+                s,
+                t,
+                delta_s_dx,
+                delta_t_dx,
+                delta_s_dy,
+                delta_t_dy,
+                channel_count,
+                options,
+            )?
+            .into_iter()
+            .map(half::f16::from_f32)
+            .collect())
+    }
+
     pub fn texture_batch_16(
         &self,
         mask: TextureBatchMask,
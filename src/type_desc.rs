@@ -523,6 +523,80 @@ impl From<TypeDesc> for oiio_TypeDesc_t {
     }
 }
 
+/// Owned `std::vector<TypeDesc>` used to pass lists of [`TypeDesc`]s to and
+/// from OIIO.
+pub(crate) struct VecTypeDesc {
+    ptr: *mut oiio_VecTypeDesc_t,
+}
+
+impl VecTypeDesc {
+    pub(crate) fn new() -> Self {
+        let mut ptr = std::mem::MaybeUninit::<*mut oiio_VecTypeDesc_t>::uninit();
+
+        unsafe {
+            oiio_VecTypeDesc_default(&raw mut ptr as _);
+
+            Self {
+                ptr: ptr.assume_init(),
+            }
+        }
+    }
+
+    pub(crate) fn push(&mut self, type_desc: &TypeDesc) {
+        unsafe {
+            oiio_VecTypeDesc_push_back(self.ptr, type_desc.into());
+        }
+    }
+
+    pub(crate) fn to_vec(&self) -> Vec<TypeDesc> {
+        let mut len = std::mem::MaybeUninit::<usize>::uninit();
+        let mut data = std::mem::MaybeUninit::<*const oiio_TypeDesc_t>::uninit();
+
+        unsafe {
+            oiio_VecTypeDesc_size(self.ptr, &raw mut len as _);
+            let len = len.assume_init();
+
+            if 0 == len {
+                return Vec::new();
+            }
+
+            oiio_VecTypeDesc_data_const(self.ptr, &raw mut data as _);
+
+            std::slice::from_raw_parts(data.assume_init(), len)
+                .iter()
+                .map(TypeDesc::from)
+                .collect()
+        }
+    }
+
+    pub(crate) fn as_raw_ptr(&self) -> *const oiio_VecTypeDesc_t {
+        self.ptr
+    }
+
+    pub(crate) fn as_raw_ptr_mut(&mut self) -> *mut oiio_VecTypeDesc_t {
+        self.ptr
+    }
+}
+
+impl From<&[TypeDesc]> for VecTypeDesc {
+    fn from(type_descs: &[TypeDesc]) -> Self {
+        let mut vec_type_desc = Self::new();
+        type_descs
+            .iter()
+            .for_each(|type_desc| vec_type_desc.push(type_desc));
+
+        vec_type_desc
+    }
+}
+
+impl Drop for VecTypeDesc {
+    fn drop(&mut self) {
+        unsafe {
+            oiio_VecTypeDesc_dtor(self.ptr);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;